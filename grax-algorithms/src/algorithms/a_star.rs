@@ -0,0 +1,356 @@
+use super::ShortestPathFinder;
+use crate::{distances::Distances, parents::Parents, path::ShortestPath, tree::ShortestPathTree};
+
+use grax_core::collections::{IndexNode, Keyed, NodeCount, VisitNodeMap};
+use grax_core::edge::{weight::*, *};
+use grax_core::graph::{EdgeIterAdjacent, NodeAttribute};
+use grax_core::node::weight::Position;
+use grax_core::prelude::*;
+use orx_priority_queue::{DaryHeap, PriorityQueue};
+use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+/// Estimates the remaining cost from a node to the target.
+/// To guarantee shortest paths the estimate must never decrease by more than
+/// the cost of an edge along that edge (consistent heuristic).
+pub trait Heuristic<C, G>
+where
+    G: Keyed,
+{
+    fn estimate(&self, graph: &G, node: NodeId<G::Key>, target: NodeId<G::Key>) -> C;
+}
+
+impl<C, G, F> Heuristic<C, G> for F
+where
+    G: Keyed,
+    F: Fn(&G, NodeId<G::Key>, NodeId<G::Key>) -> C,
+{
+    fn estimate(&self, graph: &G, node: NodeId<G::Key>, target: NodeId<G::Key>) -> C {
+        self(graph, node, target)
+    }
+}
+
+/// Sum of the absolute coordinate differences of the node positions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Manhattan;
+
+impl<C, G> Heuristic<C, G> for Manhattan
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + Sub<C, Output = C>,
+    G: IndexNode,
+    G::NodeWeight: Position<C>,
+{
+    fn estimate(&self, graph: &G, node: NodeId<G::Key>, target: NodeId<G::Key>) -> C {
+        graph[node]
+            .position()
+            .iter()
+            .zip(graph[target].position())
+            .fold(C::default(), |acc, (&a, &b)| {
+                acc + if a > b { a - b } else { b - a }
+            })
+    }
+}
+
+/// Straight line distance between the node positions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Euclidean;
+
+macro_rules! impl_euclidean(
+    ( $( $t:ident ),* )=> {
+        $(
+            impl<G> Heuristic<$t, G> for Euclidean
+            where
+                G: IndexNode,
+                G::NodeWeight: Position<$t>,
+            {
+                fn estimate(&self, graph: &G, node: NodeId<G::Key>, target: NodeId<G::Key>) -> $t {
+                    graph[node]
+                        .position()
+                        .iter()
+                        .zip(graph[target].position())
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<$t>()
+                        .sqrt()
+                }
+            }
+        )*
+    }
+);
+
+impl_euclidean!(f32, f64);
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AStar<H> {
+    pub heuristic: H,
+}
+
+impl<H> AStar<H> {
+    pub fn new(heuristic: H) -> Self {
+        Self { heuristic }
+    }
+}
+
+impl<C, G, H> ShortestPathFinder<C, G> for AStar<H>
+where
+    C: Default + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    G: EdgeIterAdjacent + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
+    H: Heuristic<C, G> + Copy,
+{
+    fn shortest_path_where<F>(
+        self,
+        graph: &G,
+        from: NodeId<G::Key>,
+        to: NodeId<G::Key>,
+        filter: F,
+    ) -> Option<ShortestPath<C, G>>
+    where
+        F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    {
+        a_star_to_where(graph, from, to, self.heuristic, filter)
+    }
+
+    /// Without a target every estimate is zero,
+    /// so that the search explores the graph like dijkstra
+    fn shortest_path_tree_where<F>(
+        self,
+        graph: &G,
+        from: NodeId<G::Key>,
+        filter: F,
+    ) -> ShortestPathTree<C, G>
+    where
+        F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    {
+        let (distances, parents) = _a_star(graph, from, None, |_| C::default(), filter);
+
+        ShortestPathTree {
+            from,
+            distances,
+            parents,
+        }
+    }
+}
+
+pub fn a_star_to<C, G, H>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    to: NodeId<G::Key>,
+    heuristic: H,
+) -> Option<ShortestPath<C, G>>
+where
+    C: Default + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    G: EdgeIterAdjacent + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
+    H: Heuristic<C, G>,
+{
+    a_star_to_where(graph, from, to, heuristic, |_| true)
+}
+
+pub fn a_star_to_where<C, F, G, H>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    to: NodeId<G::Key>,
+    heuristic: H,
+    filter: F,
+) -> Option<ShortestPath<C, G>>
+where
+    C: Default + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    G: EdgeIterAdjacent + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
+    H: Heuristic<C, G>,
+{
+    let (distances, parents) = _a_star(
+        graph,
+        from,
+        Some(to),
+        |node| heuristic.estimate(graph, node, to),
+        filter,
+    );
+
+    let distance = distances.distance(to).copied()?;
+
+    Some(ShortestPath {
+        distance,
+        from,
+        to,
+        distances,
+        parents,
+    })
+}
+
+/// Expands nodes ordered by their distance plus the estimate
+/// and stops as soon as the target is expanded.
+pub(crate) fn _a_star<C, E, F, G>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    to: Option<NodeId<G::Key>>,
+    estimate: E,
+    filter: F,
+) -> (Distances<C, G>, Parents<G>)
+where
+    C: Default + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    E: Fn(NodeId<G::Key>) -> C,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    G: EdgeIterAdjacent + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
+{
+    let mut priority_queue = DaryHeap::<_, _, 4>::with_capacity(graph.node_count());
+    let mut distances = Distances::new(graph);
+    let mut parents = Parents::new(graph);
+    let mut closed = graph.visit_node_map();
+
+    distances.update(from, C::default());
+    priority_queue.push(from, estimate(from));
+
+    while let Some((node, _)) = priority_queue.pop() {
+        if closed.is_visited(node) {
+            continue;
+        }
+        closed.visit(node);
+
+        if Some(node) == to {
+            break;
+        }
+
+        let dist = *distances.distance(node).unwrap();
+
+        for edge @ EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(node) {
+            let to = edge_id.to();
+
+            if closed.is_visited(to) || !filter(edge) {
+                continue;
+            }
+
+            let next = dist + *weight.cost();
+
+            if let Some(&prev) = distances.distance(to)
+                && prev <= next
+            {
+                continue;
+            }
+
+            parents.insert(node, to);
+            distances.update(to, next);
+            priority_queue.push(to, next + estimate(to));
+        }
+    }
+
+    (distances, parents)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::{AStar, Euclidean, Manhattan, a_star_to};
+    use crate::algorithms::{ShortestPathFinder, bellman_ford_to};
+    use crate::test::{digraph, id};
+    use grax_core::edge::EdgeRef;
+    use grax_core::index::NodeId;
+    use grax_impl::*;
+    use test::Bencher;
+
+    const SIZE: usize = 10;
+
+    /// Undirected grid where every node is positioned at its coordinates
+    fn grid() -> AdjGraph<[f64; 2], f64> {
+        let nodes = (0..SIZE * SIZE).map(|node| [(node % SIZE) as f64, (node / SIZE) as f64]);
+        let edges = (0..SIZE * SIZE).flat_map(|node| {
+            let right = (node % SIZE + 1 < SIZE).then_some(node + 1);
+            let down = (node / SIZE + 1 < SIZE).then_some(node + SIZE);
+
+            [right, down]
+                .into_iter()
+                .flatten()
+                .flat_map(move |next| [(node, next, 1.0), (next, node, 1.0)])
+        });
+
+        AdjGraph::with(nodes, edges, SIZE * SIZE)
+    }
+
+    fn node(x: usize, y: usize) -> NodeId<usize> {
+        id(y * SIZE + x)
+    }
+
+    /// Blocks the column in the middle of the grid except for the first row
+    fn wall(edge: EdgeRef<usize, f64>) -> bool {
+        let to = *edge.edge_id.to();
+        !(to % SIZE == SIZE / 2 && to / SIZE >= 1)
+    }
+
+    #[bench]
+    fn a_star_grid_manhattan(b: &mut Bencher) {
+        let graph = grid();
+
+        b.iter(|| {
+            let path = AStar::new(Manhattan)
+                .shortest_path(&graph, node(0, 0), node(9, 9))
+                .unwrap();
+            assert_eq!(path.distance, 18.0);
+        })
+    }
+
+    #[bench]
+    fn a_star_grid_euclidean_where(b: &mut Bencher) {
+        let graph = grid();
+
+        b.iter(|| {
+            let path = AStar::new(Euclidean)
+                .shortest_path_where(&graph, node(0, 9), node(9, 9), wall)
+                .unwrap();
+            let expected = bellman_ford_to(&graph, node(0, 9), node(9, 9), wall).unwrap();
+
+            assert_eq!(path.distance, 27.0);
+            assert_eq!(path.distance, expected.distance);
+        })
+    }
+
+    #[bench]
+    fn a_star_grid_unreachable(b: &mut Bencher) {
+        let graph = grid();
+
+        b.iter(|| {
+            let path =
+                AStar::new(Manhattan).shortest_path_where(&graph, node(0, 9), node(9, 9), |edge| {
+                    *edge.edge_id.to() % SIZE != SIZE / 2
+                });
+            assert!(path.is_none());
+        })
+    }
+
+    #[bench]
+    fn a_star_grid_tree(b: &mut Bencher) {
+        let graph = grid();
+
+        b.iter(|| {
+            let tree = AStar::new(Manhattan).shortest_path_tree(&graph, node(0, 0));
+            assert_eq!(tree.distances.distance(node(9, 9)), Some(&18.0));
+            assert_eq!(tree.distances.distance(node(4, 7)), Some(&11.0));
+        })
+    }
+
+    #[bench]
+    fn a_star_g_1_2_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let total = a_star_to(&graph, id(0), id(1), |_: &_, _, _| 0.0)
+                .unwrap()
+                .distance;
+            assert_eq!(total as f32, 5.56283)
+        })
+    }
+
+    #[bench]
+    fn a_star_g_1_2_di_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let total = a_star_to(&graph, id(0), id(1), |_: &_, _, _| 0.0)
+                .unwrap()
+                .distance;
+            assert_eq!(total as f32, 5.56283)
+        })
+    }
+}
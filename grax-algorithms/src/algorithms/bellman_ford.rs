@@ -140,7 +140,7 @@ where
     }
 }
 
-fn relax<C, F, G>(
+pub(crate) fn relax<C, F, G>(
     graph: &G,
     distances: &mut Distances<C, G>,
     parents: &mut Parents<G>,
@@ -0,0 +1,306 @@
+use crate::{distances::Distances, parents::Parents, path::ShortestPath};
+
+use grax_core::collections::NodeCount;
+use grax_core::edge::{weight::*, *};
+use grax_core::graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, NodeAttribute};
+use grax_core::prelude::*;
+use orx_priority_queue::{DaryHeap, PriorityQueue};
+use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+pub fn bidirectional_dijkstra<C, G>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    to: NodeId<G::Key>,
+) -> Option<ShortestPath<C, G>>
+where
+    C: Default + Copy + Add<C, Output = C> + Sub<C, Output = C> + Debug + PartialOrd,
+    G: EdgeIterAdjacent + EdgeIterAdjacentInbound + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
+{
+    bidirectional_dijkstra_where(graph, from, to, |_| true)
+}
+
+/// Searches forward from the source along outgoing edges and backward from the target
+/// along incoming edges, until the sum of both frontiers exceeds the best known path.
+/// Expects non-negative costs.
+pub fn bidirectional_dijkstra_where<C, F, G>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    to: NodeId<G::Key>,
+    filter: F,
+) -> Option<ShortestPath<C, G>>
+where
+    C: Default + Copy + Add<C, Output = C> + Sub<C, Output = C> + Debug + PartialOrd,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    G: EdgeIterAdjacent + EdgeIterAdjacentInbound + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
+{
+    let mut forward_queue = DaryHeap::<_, _, 4>::with_capacity(graph.node_count());
+    let mut backward_queue = DaryHeap::<_, _, 4>::with_capacity(graph.node_count());
+    let mut forward_distances = Distances::new(graph);
+    let mut backward_distances = Distances::new(graph);
+    let mut parents = Parents::new(graph);
+    let mut successors = graph.fixed_node_map(None);
+
+    forward_distances.update(from, C::default());
+    forward_queue.push(from, C::default());
+    backward_distances.update(to, C::default());
+    backward_queue.push(to, C::default());
+
+    // distance of the best path found so far and the node where both searches met
+    let mut best = if from == to {
+        Some((C::default(), from))
+    } else {
+        None
+    };
+
+    while let (Some(&(_, forward_top)), Some(&(_, backward_top))) =
+        (forward_queue.peek(), backward_queue.peek())
+    {
+        if let Some((distance, _)) = best
+            && forward_top + backward_top >= distance
+        {
+            break;
+        }
+
+        if forward_top <= backward_top {
+            let (node, dist) = forward_queue.pop().unwrap();
+
+            if let Some(&prev) = forward_distances.distance(node)
+                && prev < dist
+            {
+                continue;
+            }
+
+            for edge @ EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(node) {
+                if !filter(edge) {
+                    continue;
+                }
+
+                let next = dist + *weight.cost();
+                let to = edge_id.to();
+
+                if let Some(&prev) = forward_distances.distance(to)
+                    && prev <= next
+                {
+                    continue;
+                }
+
+                parents.insert(node, to);
+                forward_distances.update(to, next);
+                forward_queue.push(to, next);
+
+                if let Some(&rest) = backward_distances.distance(to) {
+                    let total = next + rest;
+                    if best.is_none_or(|(distance, _)| total < distance) {
+                        best = Some((total, to));
+                    }
+                }
+            }
+        } else {
+            let (node, dist) = backward_queue.pop().unwrap();
+
+            if let Some(&prev) = backward_distances.distance(node)
+                && prev < dist
+            {
+                continue;
+            }
+
+            for edge @ EdgeRef { edge_id, weight } in graph.iter_inbound_edges(node) {
+                if !filter(edge) {
+                    continue;
+                }
+
+                let next = dist + *weight.cost();
+                let from = edge_id.from();
+
+                if let Some(&prev) = backward_distances.distance(from)
+                    && prev <= next
+                {
+                    continue;
+                }
+
+                successors[from] = Some(node);
+                backward_distances.update(from, next);
+                backward_queue.push(from, next);
+
+                if let Some(&rest) = forward_distances.distance(from) {
+                    let total = rest + next;
+                    if best.is_none_or(|(distance, _)| total < distance) {
+                        best = Some((total, from));
+                    }
+                }
+            }
+        }
+    }
+
+    let (distance, meet) = best?;
+
+    // continue the forward tree along the backward search until the target
+    let mut node = meet;
+    while node != to {
+        let next = successors[node].unwrap();
+        let rest = *backward_distances.distance(next).unwrap();
+
+        parents.insert(node, next);
+        forward_distances.update(next, distance - rest);
+        node = next;
+    }
+
+    Some(ShortestPath {
+        distance,
+        from,
+        to,
+        distances: forward_distances,
+        parents,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::bidirectional_dijkstra;
+    use crate::algorithms::dijkstra_to;
+    use crate::test::{digraph, id, undigraph};
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    #[bench]
+    fn bidirectional_dijkstra_g_1_2_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let total = bidirectional_dijkstra(&graph, id(0), id(1))
+                .unwrap()
+                .distance;
+            assert_eq!(total as f32, 5.56283)
+        })
+    }
+
+    #[bench]
+    fn bidirectional_dijkstra_g_1_2_undi_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let total = bidirectional_dijkstra(&graph, id(0), id(1))
+                .unwrap()
+                .distance;
+            assert_eq!(total as f32, 2.36802)
+        })
+    }
+
+    #[bench]
+    fn bidirectional_dijkstra_g_1_2_di_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let total = bidirectional_dijkstra(&graph, id(0), id(1))
+                .unwrap()
+                .distance;
+            assert_eq!(total as f32, 5.56283)
+        })
+    }
+
+    #[bench]
+    fn bidirectional_dijkstra_g_1_2_di_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let total = bidirectional_dijkstra(&graph, id(0), id(1))
+                .unwrap()
+                .distance;
+            assert_eq!(total as f32, 5.56283)
+        })
+    }
+
+    #[bench]
+    fn bidirectional_dijkstra_wege_1_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Wege1.txt").unwrap();
+
+        b.iter(|| {
+            let total = bidirectional_dijkstra(&graph, id(2), id(0))
+                .unwrap()
+                .distance;
+            assert_eq!(total as f32, 6.0)
+        })
+    }
+
+    #[bench]
+    fn bidirectional_dijkstra_g_1_20_di_path_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_20.txt").unwrap();
+
+        b.iter(|| {
+            for to in [1, 42, 500, 999] {
+                let path = bidirectional_dijkstra(&graph, id(0), id(to)).unwrap();
+                let expected = dijkstra_to(&graph, id(0), id(to)).unwrap();
+                assert_le!((path.distance - expected.distance).abs(), 1e-9);
+
+                let cost = path
+                    .parents
+                    .iter_edges_to(id(0), id(to))
+                    .map(|edge_id| graph[edge_id])
+                    .sum::<f64>();
+                assert_le!((cost - path.distance).abs(), 1e-9);
+            }
+        })
+    }
+
+    // the pairs of the large graph are shared with the plain dijkstra bench to compare both
+    #[cfg(feature = "extensive")]
+    const PAIRS: [(usize, usize); 4] = [(0, 1), (17, 40_000), (512, 77_777), (99_999, 3)];
+
+    #[cfg(feature = "extensive")]
+    #[bench]
+    fn bidirectional_dijkstra_g_100_200_di_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_100_200.txt").unwrap();
+        let expected =
+            PAIRS.map(|(from, to)| dijkstra_to(&graph, id(from), id(to)).map(|path| path.distance));
+
+        b.iter(|| {
+            for ((from, to), expected) in PAIRS.into_iter().zip(expected) {
+                let total =
+                    bidirectional_dijkstra(&graph, id(from), id(to)).map(|path| path.distance);
+                assert_eq!(total.is_some(), expected.is_some());
+                assert_le!(
+                    (total.unwrap_or_default() - expected.unwrap_or_default()).abs(),
+                    1e-9
+                );
+            }
+        })
+    }
+
+    #[cfg(feature = "extensive")]
+    #[bench]
+    fn bidirectional_dijkstra_baseline_g_100_200_di_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_100_200.txt").unwrap();
+
+        b.iter(|| {
+            for (from, to) in PAIRS {
+                test::black_box(dijkstra_to(&graph, id(from), id(to)));
+            }
+        })
+    }
+
+    #[cfg(feature = "extensive")]
+    #[bench]
+    fn bidirectional_dijkstra_g_100_200_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_100_200.txt").unwrap();
+        let expected =
+            PAIRS.map(|(from, to)| dijkstra_to(&graph, id(from), id(to)).map(|path| path.distance));
+
+        b.iter(|| {
+            for ((from, to), expected) in PAIRS.into_iter().zip(expected) {
+                let total =
+                    bidirectional_dijkstra(&graph, id(from), id(to)).map(|path| path.distance);
+                assert_eq!(total.is_some(), expected.is_some());
+                assert_le!(
+                    (total.unwrap_or_default() - expected.unwrap_or_default()).abs(),
+                    1e-9
+                );
+            }
+        })
+    }
+}
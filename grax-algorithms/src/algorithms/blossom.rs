@@ -0,0 +1,336 @@
+use crate::matching::Matching;
+
+use grax_core::collections::{EdgeIter, NodeIter};
+use grax_core::edge::*;
+use grax_core::graph::NodeAttribute;
+use grax_core::index::EdgeId;
+use std::collections::VecDeque;
+
+const NONE: usize = usize::MAX;
+
+/// Edmonds' blossom algorithm for a maximum cardinality matching of a general graph.
+/// Searches an augmenting path from every unmatched node,
+/// where odd cycles are contracted into the base of their blossom.
+pub fn edmonds_blossom<G>(graph: &G) -> Matching<G>
+where
+    G: NodeAttribute + NodeIter + EdgeIter,
+{
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+    let mut indices = graph.fixed_node_map(0);
+
+    for (index, &node_id) in node_ids.iter().enumerate() {
+        indices[node_id] = index;
+    }
+
+    let mut adjacent = vec![Vec::new(); node_ids.len()];
+
+    for EdgeRef { edge_id, .. } in graph.iter_edges() {
+        let from = indices[edge_id.from()];
+        let to = indices[edge_id.to()];
+
+        // directed edges are matched in both directions
+        if from != to {
+            adjacent[from].push(to);
+            adjacent[to].push(from);
+        }
+    }
+
+    let mut search = Search::new(adjacent);
+
+    for root in 0..node_ids.len() {
+        if search.mates[root] == NONE
+            && let Some(end) = search.augmenting_path(root)
+        {
+            search.augment(end);
+        }
+    }
+
+    let mut matching = Matching::new(graph);
+
+    for (index, &mate) in search.mates.iter().enumerate() {
+        if mate == NONE {
+            continue;
+        }
+
+        let (from, to) = (node_ids[index], node_ids[mate]);
+        matching.mates[from] = Some(to);
+
+        if index < mate {
+            matching.edge_ids.push(EdgeId::new_unchecked(from, to));
+        }
+    }
+
+    matching
+}
+
+struct Search {
+    adjacent: Vec<Vec<usize>>,
+    mates: Vec<usize>,
+    /// Predecessor of every odd node in the alternating tree
+    parents: Vec<usize>,
+    /// Base of the outermost blossom of every node
+    bases: Vec<usize>,
+    used: Vec<bool>,
+    queue: VecDeque<usize>,
+}
+
+impl Search {
+    fn new(adjacent: Vec<Vec<usize>>) -> Self {
+        let node_count = adjacent.len();
+
+        Self {
+            adjacent,
+            mates: vec![NONE; node_count],
+            parents: vec![NONE; node_count],
+            bases: (0..node_count).collect(),
+            used: vec![false; node_count],
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Grows an alternating tree from the root
+    /// Returns the unmatched node at the end of an augmenting path
+    fn augmenting_path(&mut self, root: usize) -> Option<usize> {
+        self.used.fill(false);
+        self.parents.fill(NONE);
+        for (node, base) in self.bases.iter_mut().enumerate() {
+            *base = node;
+        }
+
+        self.used[root] = true;
+        self.queue.clear();
+        self.queue.push_back(root);
+
+        while let Some(from) = self.queue.pop_front() {
+            for index in 0..self.adjacent[from].len() {
+                let to = self.adjacent[from][index];
+
+                if self.bases[from] == self.bases[to] || self.mates[from] == to {
+                    continue;
+                }
+
+                if to == root || (self.mates[to] != NONE && self.parents[self.mates[to]] != NONE) {
+                    // both nodes are even, so the edge closes a blossom
+                    let base = self.common_ancestor(from, to);
+                    let mut blossom = vec![false; self.mates.len()];
+
+                    self.mark_path(from, base, to, &mut blossom);
+                    self.mark_path(to, base, from, &mut blossom);
+
+                    for node in 0..self.mates.len() {
+                        if blossom[self.bases[node]] {
+                            self.bases[node] = base;
+
+                            if !self.used[node] {
+                                self.used[node] = true;
+                                self.queue.push_back(node);
+                            }
+                        }
+                    }
+                } else if self.parents[to] == NONE {
+                    self.parents[to] = from;
+
+                    if self.mates[to] == NONE {
+                        return Some(to);
+                    }
+
+                    let mate = self.mates[to];
+                    self.used[mate] = true;
+                    self.queue.push_back(mate);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn common_ancestor(&self, mut first: usize, mut second: usize) -> usize {
+        let mut ancestors = vec![false; self.mates.len()];
+
+        loop {
+            first = self.bases[first];
+            ancestors[first] = true;
+
+            if self.mates[first] == NONE {
+                break;
+            }
+            first = self.parents[self.mates[first]];
+        }
+
+        loop {
+            second = self.bases[second];
+
+            if ancestors[second] {
+                return second;
+            }
+            second = self.parents[self.mates[second]];
+        }
+    }
+
+    /// Marks the blossoms on the path to the base and
+    /// lets the odd nodes point into the direction of the closing edge
+    fn mark_path(&mut self, mut node: usize, base: usize, mut child: usize, blossom: &mut [bool]) {
+        while self.bases[node] != base {
+            let mate = self.mates[node];
+
+            blossom[self.bases[node]] = true;
+            blossom[self.bases[mate]] = true;
+            self.parents[node] = child;
+
+            child = mate;
+            node = self.parents[mate];
+        }
+    }
+
+    fn augment(&mut self, mut node: usize) {
+        while node != NONE {
+            let parent = self.parents[node];
+            let next = self.mates[parent];
+
+            self.mates[node] = parent;
+            self.mates[parent] = node;
+            node = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::edmonds_blossom;
+    use crate::algorithms::hopcroft_karp;
+    use crate::test::{id, undigraph};
+    use grax_core::collections::GetEdge;
+    use grax_impl::*;
+    use test::Bencher;
+
+    fn undirected(edges: &[(usize, usize)], node_count: usize) -> AdjGraph<(), ()> {
+        AdjGraph::<(), ()>::with_edges(
+            edges
+                .iter()
+                .flat_map(|&(from, to)| [(from, to, ()), (to, from, ())]),
+            node_count,
+        )
+    }
+
+    #[bench]
+    fn edmonds_blossom_odd_cycle_adj_list(b: &mut Bencher) {
+        // a triangle with a tail on every corner needs the blossom to be matched perfectly
+        let graph = undirected(&[(0, 1), (1, 2), (2, 0), (0, 3), (1, 4), (2, 5)], 6);
+
+        b.iter(|| {
+            let matching = edmonds_blossom(&graph);
+            assert!(matching.is_perfect());
+
+            for edge_id in &matching.edge_ids {
+                assert!(graph.contains_edge_id(*edge_id));
+                assert_eq!(matching.mate(edge_id.to()), Some(edge_id.from()));
+            }
+        })
+    }
+
+    #[bench]
+    fn edmonds_blossom_petersen_adj_list(b: &mut Bencher) {
+        let graph = undirected(
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 0),
+                (0, 5),
+                (1, 6),
+                (2, 7),
+                (3, 8),
+                (4, 9),
+                (5, 7),
+                (7, 9),
+                (9, 6),
+                (6, 8),
+                (8, 5),
+            ],
+            10,
+        );
+
+        b.iter(|| {
+            let matching = edmonds_blossom(&graph);
+            assert_eq!(matching.len(), 5);
+        })
+    }
+
+    #[bench]
+    fn edmonds_blossom_star_adj_list(b: &mut Bencher) {
+        let graph = undirected(&[(0, 1), (0, 2), (0, 3), (0, 4)], 5);
+
+        b.iter(|| {
+            let matching = edmonds_blossom(&graph);
+            assert_eq!(matching.len(), 1);
+            assert!(matching.is_matched(id(0)));
+        })
+    }
+
+    #[bench]
+    fn edmonds_blossom_grid_hopcroft_karp_csr_mat(b: &mut Bencher) {
+        let mut edges = Vec::new();
+        for node in 0..99 {
+            if node % 11 != 10 {
+                edges.extend([(node, node + 1, ()), (node + 1, node, ())]);
+            }
+            if node + 11 < 99 {
+                edges.extend([(node, node + 11, ()), (node + 11, node, ())]);
+            }
+        }
+        let graph = CsrGraph::<(), ()>::with_edges(edges, 99);
+
+        b.iter(|| {
+            let matching = edmonds_blossom(&graph);
+            let expected = hopcroft_karp(&graph, |node| (*node / 11 + *node % 11) % 2 == 0);
+
+            assert_eq!(matching.len(), expected.len());
+        })
+    }
+
+    #[bench]
+    fn edmonds_blossom_brute_force_adj_list(b: &mut Bencher) {
+        fn maximum(edges: &[(usize, usize)], used: &mut [bool]) -> usize {
+            let Some((&(from, to), rest)) = edges.split_first() else {
+                return 0;
+            };
+
+            let mut max = maximum(rest, used);
+
+            if !used[from] && !used[to] {
+                used[from] = true;
+                used[to] = true;
+                max = max.max(1 + maximum(rest, used));
+                used[from] = false;
+                used[to] = false;
+            }
+
+            max
+        }
+
+        let edges = (0..24)
+            .map(|index| (index * 5 % 13, (index * index + 3) % 13))
+            .filter(|(from, to)| from != to)
+            .collect::<Vec<_>>();
+        let graph = undirected(&edges, 13);
+        let expected = maximum(&edges, &mut [false; 13]);
+
+        b.iter(|| {
+            let matching = edmonds_blossom(&graph);
+            assert_eq!(matching.len(), expected);
+        })
+    }
+
+    #[bench]
+    fn edmonds_blossom_k_10_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10.txt").unwrap();
+
+        b.iter(|| {
+            let matching = edmonds_blossom(&graph);
+            assert!(matching.is_perfect());
+        })
+    }
+}
@@ -0,0 +1,319 @@
+use super::{MstBuilder, UnionFind};
+use crate::{
+    tree::{Msf, Mst, Tree},
+    weight::TotalOrd,
+};
+
+use grax_core::collections::NodeIter;
+use grax_core::edge::{weight::*, *};
+use grax_core::graph::{EdgeAttribute, EdgeIterAdjacent, NodeAttribute};
+use grax_core::index::EdgeId;
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::AddAssign;
+
+#[derive(Clone, Copy)]
+pub struct Boruvka;
+
+impl<C, G> MstBuilder<C, G> for Boruvka
+where
+    C: TotalOrd + Default + AddAssign + Copy + Debug + PartialEq + Send + Sync,
+    G: NodeIter + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    fn mst(self, graph: &G) -> Option<Mst<C, G>> {
+        boruvka(graph)
+    }
+
+    fn msf(self, graph: &G) -> Msf<C, G> {
+        boruvka_forest(graph)
+    }
+}
+
+/// Borůvka's algorithm, which adds the cheapest edge leaving every component each round,
+/// so that the number of components at least halves.
+/// The cheapest edges of the nodes are searched in parallel
+/// and the components are merged with the union find.
+pub fn boruvka<C, G>(graph: &G) -> Option<Mst<C, G>>
+where
+    C: TotalOrd + Default + AddAssign + Copy + Debug + PartialEq + Send + Sync,
+    G: NodeIter + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    let first = graph.node_ids().next()?;
+    let msf = boruvka_forest(graph);
+    let cost = msf.cost();
+
+    let tree = Tree {
+        root: msf.trees[&msf.components[first]].root,
+        parents: msf.parents,
+    };
+
+    Some(Mst { tree, cost })
+}
+
+/// Borůvka's algorithm until no component has an edge to another component left
+pub fn boruvka_forest<C, G>(graph: &G) -> Msf<C, G>
+where
+    C: TotalOrd + Default + AddAssign + Copy + Debug + PartialEq + Send + Sync,
+    G: NodeIter + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    let Merged {
+        union_find,
+        costs,
+        edge_ids,
+    } = merge_components(graph);
+    union_find.into_forest(graph, &costs, &edge_ids)
+}
+
+/// Components merged by the union find with the cost of every component at its root
+struct Merged<C: Clone + Debug + PartialEq, G: NodeAttribute> {
+    union_find: UnionFind<G>,
+    costs: G::FixedNodeMap<C>,
+    /// Edges accepted to merge the components
+    edge_ids: Vec<EdgeId<G::Key>>,
+}
+
+fn merge_components<C, G>(graph: &G) -> Merged<C, G>
+where
+    C: TotalOrd + Default + AddAssign + Copy + Debug + PartialEq + Send + Sync,
+    G: NodeIter + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+
+    let mut indices = graph.fixed_node_map(0);
+    for (index, &node_id) in node_ids.iter().enumerate() {
+        indices[node_id] = index;
+    }
+
+    // the adjacency is copied once, so that the scans do not share the graph between threads
+    let adjacent = node_ids
+        .iter()
+        .map(|&from| {
+            graph
+                .iter_adjacent_edges(from)
+                .map(|EdgeRef { edge_id, weight }| (indices[edge_id.to()], *weight.cost()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut union_find = UnionFind::new(graph);
+    let mut costs = graph.fixed_node_map(C::default());
+    let mut edge_ids = Vec::new();
+
+    loop {
+        let components = node_ids
+            .iter()
+            .map(|&node_id| indices[union_find.find(node_id)])
+            .collect::<Vec<_>>();
+
+        let candidates = adjacent
+            .par_iter()
+            .enumerate()
+            .filter_map(|(from, edges)| {
+                edges
+                    .iter()
+                    .filter(|(to, _)| components[*to] != components[from])
+                    .map(|&(to, cost)| (cost, from, to))
+                    .min_by(cheaper)
+            })
+            .collect::<Vec<_>>();
+
+        let mut cheapest = vec![None; node_ids.len()];
+
+        for candidate in candidates {
+            let component = components[candidate.1];
+
+            if cheapest[component].is_none_or(|best| cheaper(&candidate, &best).is_lt()) {
+                cheapest[component] = Some(candidate);
+            }
+        }
+
+        let mut merged = false;
+
+        for (cost, from, to) in cheapest.into_iter().flatten() {
+            let root_from = union_find.find(node_ids[from]);
+            let root_to = union_find.find(node_ids[to]);
+
+            // both components may have chosen the same edge
+            if root_from != root_to {
+                let mut total = costs[root_from];
+                total += costs[root_to];
+                total += cost;
+
+                let root = union_find.union(root_from, root_to);
+                costs[root] = total;
+                edge_ids.push(EdgeId::new_unchecked(node_ids[from], node_ids[to]));
+                merged = true;
+            }
+        }
+
+        if !merged {
+            break;
+        }
+    }
+
+    Merged {
+        union_find,
+        costs,
+        edge_ids,
+    }
+}
+
+/// Orders equally expensive edges by their nodes regardless of the direction,
+/// so that both endpoints choose the same edge
+fn cheaper<C: TotalOrd>(a: &(C, usize, usize), b: &(C, usize, usize)) -> Ordering {
+    let key = |&(_, from, to): &(C, usize, usize)| (Ord::min(from, to), Ord::max(from, to));
+
+    a.0.total_ord(&b.0).then_with(|| key(a).cmp(&key(b)))
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::boruvka;
+    use crate::algorithms::kruskal;
+    use crate::test::undigraph;
+    use grax_core::collections::{GetEdge, NodeCount};
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    #[bench]
+    fn boruvka_graph_1_2_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 287.32286);
+        })
+    }
+
+    #[bench]
+    fn boruvka_graph_1_20_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_20.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 36.86275);
+        })
+    }
+
+    #[bench]
+    fn boruvka_graph_1_200_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_200.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 12.68182);
+        })
+    }
+
+    #[bench]
+    fn boruvka_graph_10_20_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_10_20.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 2785.62417);
+        })
+    }
+
+    #[bench]
+    fn boruvka_graph_10_200_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_10_200.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 372.14417);
+        })
+    }
+
+    #[cfg(feature = "extensive")]
+    #[bench]
+    fn boruvka_graph_100_200_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_100_200.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 27550.51488);
+        })
+    }
+
+    #[bench]
+    fn boruvka_graph_1_2_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _> = undigraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 287.32286);
+        })
+    }
+
+    #[bench]
+    fn boruvka_graph_10_200_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _> = undigraph("../data/G_10_200.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 372.14417);
+        })
+    }
+
+    #[bench]
+    fn boruvka_graph_1_20_hash_graph(b: &mut Bencher) {
+        let graph: HashGraph<_, _> = undigraph("../data/G_1_20.txt").unwrap();
+
+        b.iter(|| {
+            let count = boruvka(&graph).unwrap().cost as f32;
+            assert_eq!(count, 36.86275);
+        })
+    }
+
+    #[bench]
+    fn boruvka_tree_edges_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_20.txt").unwrap();
+
+        b.iter(|| {
+            let mst = boruvka(&graph).unwrap();
+            let edge_ids = mst.tree.parents.edge_ids().collect::<Vec<_>>();
+            let cost = edge_ids.iter().map(|&edge_id| graph[edge_id]).sum::<f64>();
+
+            assert_eq!(edge_ids.len(), graph.node_count() - 1);
+            assert!(
+                edge_ids
+                    .iter()
+                    .all(|&edge_id| graph.contains_edge_id(edge_id))
+            );
+            assert_le!((cost - mst.cost).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn boruvka_equal_costs_kruskal_adj_list(b: &mut Bencher) {
+        // a grid where every edge costs the same except the last row
+        let mut edges = Vec::new();
+        for node in 0..100 {
+            let cost = if node >= 90 { 2.0 } else { 1.0 };
+
+            if node % 10 != 9 {
+                edges.extend([(node, node + 1, cost), (node + 1, node, cost)]);
+            }
+            if node + 10 < 100 {
+                edges.extend([(node, node + 10, 1.0), (node + 10, node, 1.0)]);
+            }
+        }
+        let graph = AdjGraph::<(), f64>::with_edges(edges, 100);
+        let expected = kruskal(&graph).unwrap().cost;
+
+        b.iter(|| {
+            let mst = boruvka(&graph).unwrap();
+
+            assert_eq!(mst.cost, expected);
+            assert_eq!(mst.cost, 99.0);
+        })
+    }
+}
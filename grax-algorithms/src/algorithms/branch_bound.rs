@@ -1,4 +1,4 @@
-use super::{nearest_neighbor, TspSolver};
+use super::{nearest_neighbor, prim_by, TspSolver};
 use crate::{
     cycle::{Cycle, TspCycle},
     parents::Parents,
@@ -9,8 +9,10 @@ use grax_core::collections::*;
 use grax_core::edge::{weight::*, *};
 use grax_core::graph::*;
 use grax_core::prelude::*;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use std::fmt::Debug;
 use std::ops::{Add, AddAssign};
+use std::sync::RwLock;
 
 #[derive(Debug, Clone, Copy)]
 pub struct BranchBound;
@@ -163,13 +165,293 @@ pub(crate) fn _branch_bound_rec<C, G>(
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct ParallelBranchBound;
+
+impl<C, G> TspSolver<C, G> for ParallelBranchBound
+where
+    C: Debug
+        + Copy
+        + Default
+        + PartialOrd
+        + AddAssign<C>
+        + Add<C, Output = C>
+        + TotalOrd
+        + Bounded
+        + Into<f64>
+        + Send
+        + Sync,
+    G: NodeIterAdjacent
+        + EdgeIterAdjacent
+        + EdgeAttribute
+        + NodeAttribute
+        + NodeIter
+        + NodeCount
+        + IndexEdge
+        + GetEdge
+        + Sync,
+    G::EdgeWeight: Cost<C>,
+    G::FixedNodeMap<bool>: Clone + Send + Sync,
+{
+    fn solve(graph: &G) -> Option<TspCycle<C, G>> {
+        branch_bound_parallel(graph)
+    }
+}
+
+/// Depth first branch and bound, where the first levels of the search tree are explored in parallel.
+/// Partial routes are pruned by a 1-tree bound: a route completing the path from the start to the current node
+/// consists of a hamiltonian path over the unvisited nodes, which costs at least their minimum spanning tree,
+/// and the cheapest edges connecting it to the current node and back to the start.
+/// Held-Karp penalties on the unvisited nodes tighten the bound, see `one_tree_bound`.
+/// The bound relies on symmetric costs like on undirected graphs.
+pub fn branch_bound_parallel<C, G>(graph: &G) -> Option<TspCycle<C, G>>
+where
+    C: Debug
+        + Copy
+        + Default
+        + PartialOrd
+        + AddAssign<C>
+        + Add<C, Output = C>
+        + TotalOrd
+        + Bounded
+        + Into<f64>
+        + Send
+        + Sync,
+    G: NodeIterAdjacent
+        + EdgeIterAdjacent
+        + EdgeAttribute
+        + NodeAttribute
+        + NodeIter
+        + NodeCount
+        + IndexEdge
+        + GetEdge
+        + Sync,
+    G::EdgeWeight: Cost<C>,
+    G::FixedNodeMap<bool>: Clone + Send + Sync,
+{
+    let start = graph.node_ids().next()?;
+
+    // the incumbent route is shared between the threads
+    let best = match nearest_neighbor(graph) {
+        Some(route) => {
+            let mut path = route.cycle.iter().collect::<Vec<_>>();
+            path.reverse();
+            RwLock::new((route.cost, Some(path)))
+        }
+        None => RwLock::new((C::MAX, None)),
+    };
+
+    let mut visited = graph.visit_node_map();
+    visited.visit(start);
+
+    _branch_bound_parallel(graph, &best, C::default(), &mut vec![start], &mut visited);
+
+    let (cost, path) = best.into_inner().unwrap();
+    let path = path?;
+
+    let mut parents = Parents::new(graph);
+    for (&from, &to) in path.iter().zip(path.iter().cycle().skip(1)) {
+        parents.insert(from, to);
+    }
+
+    Some(TspCycle {
+        cost,
+        cycle: Cycle {
+            member: start,
+            parents,
+        },
+    })
+}
+
+/// Number of levels of the search tree, whose children are explored in parallel
+const PARALLEL_DEPTH: usize = 3;
+
+/// Cost and path of the best route found so far
+type Incumbent<C, K> = RwLock<(C, Option<Vec<NodeId<K>>>)>;
+
+fn _branch_bound_parallel<C, G>(
+    graph: &G,
+    best: &Incumbent<C, G::Key>,
+    cost: C,
+    path: &mut Vec<NodeId<G::Key>>,
+    visited: &mut G::FixedNodeMap<bool>,
+) where
+    C: Debug
+        + Copy
+        + Default
+        + PartialOrd
+        + AddAssign<C>
+        + Add<C, Output = C>
+        + TotalOrd
+        + Bounded
+        + Into<f64>
+        + Send
+        + Sync,
+    G: EdgeIterAdjacent + EdgeAttribute + NodeAttribute + NodeCount + NodeIter + GetEdge + Sync,
+    G::EdgeWeight: Cost<C>,
+    G::FixedNodeMap<bool>: Clone + Send + Sync,
+{
+    let start = path[0];
+    let from = *path.last().unwrap();
+
+    if path.len() == graph.node_count() {
+        if let Some(edge) = graph.edge(EdgeId::new_unchecked(from, start)) {
+            let cost = cost + *edge.weight.cost();
+            let mut best = best.write().unwrap();
+
+            if cost < best.0 {
+                *best = (cost, Some(path.clone()));
+            }
+        }
+        return;
+    }
+
+    let incumbent = best.read().unwrap().0;
+    if !one_tree_bound(graph, cost, from, start, visited, incumbent)
+        .is_some_and(|bound| bound < incumbent.into())
+    {
+        return;
+    }
+
+    // cheap edges first find good routes early, which prune the remaining children
+    let mut children = graph
+        .iter_adjacent_edges(from)
+        .filter(|edge| !visited.is_visited(edge.edge_id.to()))
+        .map(|edge| (edge.edge_id.to(), cost + *edge.weight.cost()))
+        .collect::<Vec<_>>();
+    children.sort_by(|a, b| a.1.total_ord(&b.1));
+
+    if path.len() <= PARALLEL_DEPTH {
+        children.into_par_iter().for_each(|(to, cost)| {
+            let mut path = path.clone();
+            let mut visited = visited.clone();
+
+            path.push(to);
+            visited.visit(to);
+
+            _branch_bound_parallel(graph, best, cost, &mut path, &mut visited);
+        });
+    } else {
+        for (to, cost) in children {
+            path.push(to);
+            visited.visit(to);
+
+            _branch_bound_parallel(graph, best, cost, path, visited);
+
+            visited.unvisit(to);
+            path.pop();
+        }
+    }
+}
+
+/// Number of subgradient steps, which tighten the 1-tree bound of a partial route
+const PENALTY_ITERATIONS: usize = 10;
+
+/// Returns the Held-Karp lower bound of any route completing the path or none if it cannot be completed.
+/// The remaining route visits every unvisited node with two edges, so adding a penalty to the cost of both edges
+/// raises its cost by twice the penalty. Every 1-tree under the penalized costs, its minimum spanning tree over the
+/// unvisited nodes and the cheapest edges connecting it to the current node and back to the start, bounds the route
+/// once the penalties are subtracted again. The penalties follow subgradient steps towards the incumbent cost,
+/// which raise them on nodes with more than two edges in the 1-tree and lower them on leaves.
+fn one_tree_bound<C, G>(
+    graph: &G,
+    cost: C,
+    from: NodeId<G::Key>,
+    start: NodeId<G::Key>,
+    visited: &G::FixedNodeMap<bool>,
+    incumbent: C,
+) -> Option<f64>
+where
+    C: Debug + Copy + Default + PartialOrd + AddAssign<C> + TotalOrd + Bounded + Into<f64>,
+    G: EdgeIterAdjacent + EdgeAttribute + NodeAttribute + NodeCount + NodeIter,
+    G::EdgeWeight: Cost<C>,
+{
+    let unvisited = graph
+        .node_ids()
+        .filter(|&node| !visited.is_visited(node))
+        .collect::<Vec<_>>();
+    let incumbent = incumbent.into();
+
+    let mut penalties = graph.fixed_node_map(0.0);
+    let mut degrees = graph.fixed_node_map(0.0);
+    let mut bound = f64::MIN;
+    let mut scale = 2.0;
+
+    for _ in 0..PENALTY_ITERATIONS {
+        let cheapest = |node: NodeId<G::Key>| {
+            graph
+                .iter_adjacent_edges(node)
+                .filter(|edge| !visited.is_visited(edge.edge_id.to()))
+                .map(|edge| {
+                    let to = edge.edge_id.to();
+                    (to, (*edge.weight.cost()).into() + penalties[to])
+                })
+                .min_by(|a, b| a.1.total_ord(&b.1))
+        };
+
+        let (root, to_path) = cheapest(from)?;
+        let (last, to_start) = cheapest(start)?;
+
+        let mst = prim_by(graph, root, |edge| {
+            let (from, to) = (edge.edge_id.from(), edge.edge_id.to());
+            (!visited.is_visited(from) && !visited.is_visited(to))
+                .then(|| (*edge.weight.cost()).into() + penalties[from] + penalties[to])
+        });
+
+        // the unvisited nodes are not connected
+        if mst.tree.parents.edge_ids().count() + 1 < unvisited.len() {
+            return None;
+        }
+
+        let penalty: f64 = unvisited.iter().map(|&node| penalties[node]).sum();
+        let tree_bound = cost.into() + to_path + to_start + mst.cost - 2.0 * penalty;
+        bound = bound.max(tree_bound);
+
+        // the incumbent is only missing without any route, where no bound prunes anything
+        if bound >= incumbent || incumbent == C::MAX.into() {
+            break;
+        }
+
+        for &node in &unvisited {
+            degrees[node] = -2.0;
+        }
+        degrees[root] += 1.0;
+        degrees[last] += 1.0;
+        for edge_id in mst.tree.parents.edge_ids() {
+            degrees[edge_id.from()] += 1.0;
+            degrees[edge_id.to()] += 1.0;
+        }
+
+        let norm: f64 = unvisited
+            .iter()
+            .map(|&node| degrees[node] * degrees[node])
+            .sum();
+
+        // every node has two edges, so the 1-tree is the cheapest route
+        if norm == 0.0 {
+            break;
+        }
+
+        let step = scale * (incumbent - tree_bound) / norm;
+        for &node in &unvisited {
+            penalties[node] += step * degrees[node];
+        }
+        scale *= 0.7;
+    }
+
+    Some(bound)
+}
+
 #[cfg(test)]
 mod test {
     extern crate test;
 
-    use super::{branch_bound, branch_bound_rec};
+    use super::{branch_bound, branch_bound_parallel, branch_bound_rec};
+    use crate::algorithms::held_karp;
     use crate::test::undigraph;
+    use grax_core::collections::NodeCount;
     use grax_impl::*;
+    use more_asserts::*;
     use test::Bencher;
 
     #[bench]
@@ -255,4 +537,84 @@ mod test {
             assert_eq!(total, 36.13);
         })
     }
+
+    #[bench]
+    fn branch_bound_parallel_k_10_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10.txt").unwrap();
+
+        b.iter(|| {
+            let route = branch_bound_parallel(&graph).unwrap();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_eq!(route.cost as f32, 38.41);
+        })
+    }
+
+    #[bench]
+    fn branch_bound_parallel_k_10e_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10e.txt").unwrap();
+
+        b.iter(|| {
+            let total = branch_bound_parallel(&graph).unwrap().cost as f32;
+            assert_eq!(total, 27.26);
+        })
+    }
+
+    #[bench]
+    fn branch_bound_parallel_k_12_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let total = branch_bound_parallel(&graph).unwrap().cost as f32;
+            assert_eq!(total, 45.19);
+        })
+    }
+
+    #[bench]
+    fn branch_bound_parallel_k_12e_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12e.txt").unwrap();
+
+        b.iter(|| {
+            let total = branch_bound_parallel(&graph).unwrap().cost as f32;
+            assert_eq!(total, 36.13);
+        })
+    }
+
+    #[bench]
+    fn branch_bound_parallel_k_15_held_karp_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_15.txt").unwrap();
+        let expected = held_karp(&graph).unwrap().cost;
+
+        b.iter(|| {
+            let route = branch_bound_parallel(&graph).unwrap();
+            let cost = route
+                .cycle
+                .iter_edges()
+                .map(|edge_id| graph[edge_id])
+                .sum::<f64>();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_le!((route.cost - expected).abs(), 1e-9);
+            assert_le!((cost - expected).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn branch_bound_parallel_k_15e_held_karp_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_15e.txt").unwrap();
+        let expected = held_karp(&graph).unwrap().cost;
+
+        b.iter(|| {
+            let route = branch_bound_parallel(&graph).unwrap();
+            let cost = route
+                .cycle
+                .iter_edges()
+                .map(|edge_id| graph[edge_id])
+                .sum::<f64>();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_le!((route.cost - expected).abs(), 1e-9);
+            assert_le!((cost - expected).abs(), 1e-9);
+        })
+    }
 }
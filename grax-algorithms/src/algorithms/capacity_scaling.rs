@@ -0,0 +1,342 @@
+use super::{
+    McfSolver, empty_flow, remove_residual_edges, residual_network::ResidualNetwork, sum_cost_flow,
+};
+use crate::flow::FlowCostBundle;
+
+use grax_core::{collections::*, graph::NodeAttribute, index::Identifier};
+use orx_priority_queue::{DaryHeap, PriorityQueue};
+use std::{
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct CapacityScaling;
+
+impl<C, G> McfSolver<C, G> for CapacityScaling
+where
+    C: Default
+        + Add<C, Output = C>
+        + AddAssign<C>
+        + Sum
+        + Sub<C, Output = C>
+        + SubAssign<C>
+        + Mul<C, Output = C>
+        + Div<C, Output = C>
+        + Neg<Output = C>
+        + From<u8>
+        + Copy
+        + PartialOrd
+        + Debug,
+    G: EdgeCollection<EdgeWeight = FlowCostBundle<C>>
+        + NodeCollection<NodeWeight = C>
+        + NodeAttribute
+        + NodeIter
+        + EdgeIter
+        + EdgeIterMut
+        + IndexEdgeMut
+        + RemoveEdge,
+{
+    fn solve(graph: &mut G) -> Option<C> {
+        capacity_scaling(graph)
+    }
+}
+
+/// Successive shortest paths restricted to augmentations of at least `delta`,
+/// which is halved every phase until every residual capacity is considered.
+/// Node potentials keep the reduced costs non-negative, so that every
+/// shortest path is found by dijkstra.
+pub fn capacity_scaling<C, G>(graph: &mut G) -> Option<C>
+where
+    C: Default
+        + Add<C, Output = C>
+        + AddAssign<C>
+        + Sum
+        + Sub<C, Output = C>
+        + SubAssign<C>
+        + Mul<C, Output = C>
+        + Div<C, Output = C>
+        + Neg<Output = C>
+        + From<u8>
+        + Copy
+        + PartialOrd
+        + Debug,
+    G: EdgeCollection<EdgeWeight = FlowCostBundle<C>>
+        + NodeCollection<NodeWeight = C>
+        + NodeAttribute
+        + NodeIter
+        + EdgeIter
+        + EdgeIterMut
+        + IndexEdgeMut
+        + RemoveEdge,
+{
+    let zero = C::default();
+    let one = C::from(1);
+    let two = C::from(2);
+
+    let mut network = ResidualNetwork::from_graph(graph);
+    let mut potentials = vec![zero; network.node_count()];
+
+    let max = network
+        .residuals
+        .iter()
+        .chain(&network.excess)
+        .map(|&value| if value < zero { -value } else { value })
+        .fold(zero, |max, value| if value > max { value } else { max });
+
+    let mut delta = one;
+    while delta + delta <= max {
+        delta = delta + delta;
+    }
+
+    // the last phase without a threshold also covers fractional capacities
+    let mut threshold = Some(delta);
+
+    loop {
+        // arcs below the previous threshold may violate the reduced costs
+        for arc in 0..network.heads.len() {
+            let residual = network.residuals[arc];
+
+            if admits(threshold, residual) && network.reduced_cost(&potentials, arc) < zero {
+                network.push(arc, residual);
+            }
+        }
+
+        while let Some(path) = shortest_path(&network, &mut potentials, threshold) {
+            let source = path.first().map(|&arc| network.tail(arc)).unwrap();
+            let sink = network.heads[*path.last().unwrap()];
+
+            let amount = path
+                .iter()
+                .map(|&arc| network.residuals[arc])
+                .chain([network.excess[source], -network.excess[sink]])
+                .fold(network.excess[source], |min, value| {
+                    if value < min { value } else { min }
+                });
+
+            for arc in path {
+                network.push(arc, amount);
+            }
+        }
+
+        threshold = match threshold {
+            Some(delta) if delta > one => Some(delta / two),
+            Some(_) => None,
+            None => break,
+        };
+    }
+
+    if network.excess.iter().any(|&excess| excess != zero) {
+        remove_residual_edges(graph);
+        empty_flow(graph);
+        return None;
+    }
+
+    network.write_flow(graph);
+    remove_residual_edges(graph);
+
+    Some(sum_cost_flow(graph))
+}
+
+fn admits<C>(threshold: Option<C>, value: C) -> bool
+where
+    C: Default + PartialOrd,
+{
+    match threshold {
+        Some(delta) => value >= delta,
+        None => value > C::default(),
+    }
+}
+
+/// Dijkstra on the reduced costs from every node with an excess of at least the threshold
+/// to the closest node with a deficit of at least the threshold.
+/// Updates the potentials and returns the arcs of the path.
+fn shortest_path<C, K>(
+    network: &ResidualNetwork<C, K>,
+    potentials: &mut [C],
+    threshold: Option<C>,
+) -> Option<Vec<usize>>
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + Sub<C, Output = C> + Neg<Output = C>,
+    K: Identifier,
+{
+    let zero = C::default();
+    let node_count = network.node_count();
+
+    let mut priority_queue = DaryHeap::<_, _, 4>::with_capacity(node_count);
+    let mut distances = vec![None; node_count];
+    let mut parents = vec![usize::MAX; node_count];
+    let mut settled = vec![false; node_count];
+
+    for (node, distance) in distances.iter_mut().enumerate() {
+        if admits(threshold, network.excess[node]) {
+            *distance = Some(zero);
+            priority_queue.push(node, zero);
+        }
+    }
+
+    let mut sink = None;
+
+    while let Some((node, dist)) = priority_queue.pop() {
+        if settled[node] {
+            continue;
+        }
+        settled[node] = true;
+
+        if admits(threshold, -network.excess[node]) {
+            sink = Some((node, dist));
+            break;
+        }
+
+        for &arc in &network.adjacent[node] {
+            if !admits(threshold, network.residuals[arc]) {
+                continue;
+            }
+
+            let to = network.heads[arc];
+            let next = dist + network.reduced_cost(potentials, arc);
+
+            if let Some(prev) = distances[to]
+                && prev <= next
+            {
+                continue;
+            }
+
+            distances[to] = Some(next);
+            parents[to] = arc;
+            priority_queue.push(to, next);
+        }
+    }
+
+    let (sink, max) = sink?;
+
+    // nodes not settled are at least as far as the sink
+    for node in 0..node_count {
+        potentials[node] = potentials[node]
+            + match distances[node] {
+                Some(distance) if settled[node] => distance,
+                _ => max,
+            };
+    }
+
+    let mut path = Vec::new();
+    let mut node = sink;
+
+    while parents[node] != usize::MAX {
+        let arc = parents[node];
+        path.push(arc);
+        node = network.tail(arc);
+    }
+
+    path.reverse();
+    Some(path)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::CapacityScaling;
+    use crate::algorithms::{McfSolver, empty_flow};
+    use crate::test::bgraph;
+    use grax_impl::*;
+    use test::Bencher;
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_1_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal1.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 3.0);
+            empty_flow(&mut graph);
+        });
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_2_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal2.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 0.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_3_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal3.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_4_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal4.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_gross_1_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross1.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1537.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_gross_2_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross2.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1838.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_gross_3_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross3.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_1_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal1.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 3.0);
+            empty_flow(&mut graph);
+        });
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_gross_1_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal_gross1.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1537.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn capacity_scaling_kostenminimal_gross_2_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal_gross2.txt").unwrap();
+        b.iter(|| {
+            let cost = CapacityScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1838.0);
+            empty_flow(&mut graph);
+        })
+    }
+}
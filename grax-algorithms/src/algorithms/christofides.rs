@@ -0,0 +1,291 @@
+use super::{_min_weight_perfect_matching, TspSolver, prim};
+use crate::{
+    cycle::{Cycle, TspCycle},
+    parents::Parents,
+    weight::{Bounded, TotalOrd},
+};
+
+use grax_core::collections::{GetEdge, NodeCount, NodeIter};
+use grax_core::edge::weight::*;
+use grax_core::graph::{EdgeAttribute, EdgeIterAdjacent, NodeAttribute};
+use grax_core::index::EdgeId;
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Christofides;
+
+impl<C, G> TspSolver<C, G> for Christofides
+where
+    C: Default
+        + Bounded
+        + TotalOrd
+        + PartialOrd
+        + Copy
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + Div<C, Output = C>
+        + AddAssign
+        + SubAssign
+        + From<u8>
+        + Debug,
+    G: NodeAttribute + EdgeAttribute + NodeIter + EdgeIterAdjacent + GetEdge + NodeCount,
+    G::EdgeWeight: Cost<C>,
+{
+    fn solve(graph: &G) -> Option<TspCycle<C, G>> {
+        christofides(graph)
+    }
+}
+
+/// Christofides' approximation of at most 1.5 times the optimal route on metric complete graphs.
+/// The odd nodes of the minimum spanning tree are matched perfectly with minimum costs,
+/// then the euler tour of the tree and the matching is shortcut to a hamiltonian cycle.
+/// Returns none if an edge required by the matching or the route is missing,
+/// while a single node is a route of zero cost.
+pub fn christofides<C, G>(graph: &G) -> Option<TspCycle<C, G>>
+where
+    C: Default
+        + Bounded
+        + TotalOrd
+        + PartialOrd
+        + Copy
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + Div<C, Output = C>
+        + AddAssign
+        + SubAssign
+        + From<u8>
+        + Debug,
+    G: NodeAttribute + EdgeAttribute + NodeIter + EdgeIterAdjacent + GetEdge + NodeCount,
+    G::EdgeWeight: Cost<C>,
+{
+    let tree = prim(graph)?.tree;
+
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+
+    if node_ids.len() == 1 {
+        let mut parents = Parents::new(graph);
+        parents.insert(tree.root, tree.root);
+
+        return Some(TspCycle {
+            cost: C::default(),
+            cycle: Cycle {
+                member: tree.root,
+                parents,
+            },
+        });
+    }
+    let mut indices = graph.fixed_node_map(0);
+
+    for (index, &node_id) in node_ids.iter().enumerate() {
+        indices[node_id] = index;
+    }
+
+    // the multigraph of the tree and the matching has only even degrees
+    let mut edges = tree
+        .parents
+        .edge_ids()
+        .map(|edge_id| (indices[edge_id.from()], indices[edge_id.to()]))
+        .collect::<Vec<_>>();
+
+    let mut degrees = vec![0; node_ids.len()];
+    for &(from, to) in &edges {
+        degrees[from] += 1;
+        degrees[to] += 1;
+    }
+
+    let odd = (0..node_ids.len())
+        .filter(|&node| degrees[node] % 2 == 1)
+        .collect::<Vec<_>>();
+    let mut odd_edges = Vec::new();
+
+    for (first, &from) in odd.iter().enumerate() {
+        for (second, &to) in odd.iter().enumerate().skip(first + 1) {
+            let edge = graph.edge(EdgeId::new_unchecked(node_ids[from], node_ids[to]))?;
+            odd_edges.push((first, second, *edge.weight.cost()));
+        }
+    }
+
+    let mates = _min_weight_perfect_matching(odd.len(), &odd_edges)?;
+
+    for (first, &second) in mates.iter().enumerate() {
+        if first < second {
+            edges.push((odd[first], odd[second]));
+        }
+    }
+
+    let tour = euler_tour(node_ids.len(), &edges, indices[tree.root]);
+
+    let mut visited = vec![false; node_ids.len()];
+    let mut route = Vec::with_capacity(node_ids.len());
+
+    for node in tour {
+        if !visited[node] {
+            visited[node] = true;
+            route.push(node_ids[node]);
+        }
+    }
+
+    let mut parents = Parents::new(graph);
+    let mut cost = C::default();
+
+    for (&from, &to) in route.iter().zip(route.iter().cycle().skip(1)) {
+        parents.insert(from, to);
+        cost += *graph.edge(EdgeId::new_unchecked(from, to))?.weight.cost();
+    }
+
+    Some(TspCycle {
+        cost,
+        cycle: Cycle {
+            member: tree.root,
+            parents,
+        },
+    })
+}
+
+/// Hierholzer's algorithm for the closed walk over every edge of a connected multigraph with even degrees
+fn euler_tour(node_count: usize, edges: &[(usize, usize)], start: usize) -> Vec<usize> {
+    let mut adjacent = vec![Vec::new(); node_count];
+
+    for (edge, &(from, to)) in edges.iter().enumerate() {
+        adjacent[from].push((edge, to));
+        adjacent[to].push((edge, from));
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut current = vec![0; node_count];
+    let mut stack = vec![start];
+    let mut tour = Vec::with_capacity(edges.len() + 1);
+
+    while let Some(&node) = stack.last() {
+        // skips the edges already walked from the other side
+        while current[node] < adjacent[node].len() && used[adjacent[node][current[node]].0] {
+            current[node] += 1;
+        }
+
+        if let Some(&(edge, to)) = adjacent[node].get(current[node]) {
+            used[edge] = true;
+            stack.push(to);
+        } else {
+            tour.push(node);
+            stack.pop();
+        }
+    }
+
+    tour
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::christofides;
+    use crate::test::undigraph;
+    use grax_core::collections::NodeCount;
+    use grax_impl::*;
+    use more_asserts::*;
+    use test::Bencher;
+
+    #[bench]
+    fn christofides_k_10_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10.txt").unwrap();
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_le!(route.cost, 38.41 * 1.5);
+            assert_ge!(route.cost, 38.41);
+        })
+    }
+
+    #[bench]
+    fn christofides_k_10e_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10e.txt").unwrap();
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_le!(route.cost, 27.26 * 1.5);
+            assert_ge!(route.cost, 27.26);
+        })
+    }
+
+    #[bench]
+    fn christofides_k_12_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_le!(route.cost, 45.19 * 1.5);
+            assert_ge!(route.cost, 45.19);
+        })
+    }
+
+    #[bench]
+    fn christofides_k_12e_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12e.txt").unwrap();
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_le!(route.cost, 36.13 * 1.5);
+            assert_ge!(route.cost, 36.13);
+        })
+    }
+
+    #[bench]
+    fn christofides_single_node_adj_list(b: &mut Bencher) {
+        let graph = AdjGraph::<(), f64>::with_edges([], 1);
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+
+            assert_eq!(route.cost, 0.0);
+            assert_eq!(
+                route.cycle.iter().collect::<Vec<_>>(),
+                vec![route.cycle.member]
+            );
+        })
+    }
+
+    #[bench]
+    fn christofides_incomplete_adj_list(b: &mut Bencher) {
+        // a path has no hamiltonian cycle
+        let graph = AdjGraph::<(), f64>::with_edges(
+            [(0, 1, 1.0), (1, 0, 1.0), (1, 2, 1.0), (2, 1, 1.0)],
+            3,
+        );
+
+        b.iter(|| {
+            assert!(christofides(&graph).is_none());
+        })
+    }
+
+    #[bench]
+    fn christofides_k_10_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _> = undigraph("../data/K_10.txt").unwrap();
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+
+            assert_le!(route.cost, 38.41 * 1.5);
+            assert_ge!(route.cost, 38.41);
+        })
+    }
+
+    #[bench]
+    fn christofides_k_12_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+
+            assert_le!(route.cost, 45.19 * 1.5);
+            assert_ge!(route.cost, 45.19);
+        })
+    }
+}
@@ -0,0 +1,381 @@
+use super::bfs;
+use crate::{
+    parents::Parents,
+    tree::{Mst, Tree, Unreachable},
+};
+
+use grax_core::collections::{EdgeIter, NodeIter};
+use grax_core::edge::weight::*;
+use grax_core::graph::{EdgeAttribute, EdgeIterAdjacent, NodeAttribute};
+use grax_core::index::NodeId;
+use std::fmt::Debug;
+use std::ops::{AddAssign, Sub};
+
+/// Edges of a contracted graph as source, target, cost and index of the original edge
+type Edges<C> = Vec<(usize, usize, C, usize)>;
+
+struct Level<C> {
+    edges: Edges<C>,
+    /// Original edge of the cheapest edge entering every node
+    cheapest: Vec<usize>,
+    cycles: Vec<Vec<usize>>,
+    cycle_of: Vec<usize>,
+}
+
+/// Chu-Liu/Edmonds algorithm for the minimum spanning arborescence of a directed graph in `O(n m)`.
+/// Every node except the root chooses its cheapest entering edge.
+/// The cycles among the chosen edges are contracted, where the edges entering a cycle
+/// are reduced by the cost of the chosen edge they would replace, until no cycle is left.
+/// Afterwards the cycles are expanded again without the edge replaced by the edge entering them.
+/// Returns the first node in the order of the node ids, which cannot be reached from the root.
+pub fn chu_liu_edmonds<C, G>(
+    graph: &G,
+    root: NodeId<G::Key>,
+) -> Result<Mst<C, G>, Unreachable<G::Key>>
+where
+    C: Default + Copy + PartialOrd + AddAssign + Sub<C, Output = C> + Debug,
+    G: NodeIter + EdgeIter + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    let reachable = bfs(graph, root);
+
+    if let Some(node_id) = graph.node_ids().find(|&node_id| !reachable[node_id]) {
+        return Err(Unreachable(node_id));
+    }
+
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+
+    let mut indices = graph.fixed_node_map(0);
+    for (index, &node_id) in node_ids.iter().enumerate() {
+        indices[node_id] = index;
+    }
+
+    // loops and edges entering the root are never part of the arborescence
+    let original = graph
+        .iter_edges()
+        .map(|edge| {
+            (
+                indices[edge.edge_id.from()],
+                indices[edge.edge_id.to()],
+                *edge.weight.cost(),
+            )
+        })
+        .filter(|&(from, to, _)| from != to && to != indices[root])
+        .collect::<Vec<_>>();
+
+    let mut edges = original
+        .iter()
+        .enumerate()
+        .map(|(id, &(from, to, cost))| (from, to, cost, id))
+        .collect::<Edges<C>>();
+
+    let mut count = node_ids.len();
+    let mut current_root = indices[root];
+    let mut levels = Vec::new();
+
+    let mut chosen = loop {
+        let mut cheapest: Vec<Option<(C, usize)>> = vec![None; count];
+
+        for (index, &(_, to, cost, _)) in edges.iter().enumerate() {
+            if cheapest[to].is_none_or(|(best, _)| cost < best) {
+                cheapest[to] = Some((cost, index));
+            }
+        }
+
+        let (cycles, cycle_of) = find_cycles(&edges, &cheapest, current_root);
+        let cheapest_ids = cheapest
+            .iter()
+            .map(|entry| entry.map_or(usize::MAX, |(_, index)| edges[index].3))
+            .collect::<Vec<_>>();
+
+        if cycles.is_empty() {
+            break cheapest_ids
+                .into_iter()
+                .filter(|&id| id != usize::MAX)
+                .collect::<Vec<_>>();
+        }
+
+        // the cycles keep their index, every other node is numbered after them
+        let mut components = vec![0; count];
+        let mut next = cycles.len();
+        for node in 0..count {
+            components[node] = if cycle_of[node] != usize::MAX {
+                cycle_of[node]
+            } else {
+                next += 1;
+                next - 1
+            };
+        }
+
+        let contracted = edges
+            .iter()
+            .filter(|&&(from, to, _, _)| components[from] != components[to])
+            .map(|&(from, to, cost, id)| {
+                let cost = match cheapest[to] {
+                    Some((replaced, _)) if cycle_of[to] != usize::MAX => cost - replaced,
+                    _ => cost,
+                };
+                (components[from], components[to], cost, id)
+            })
+            .collect::<Edges<C>>();
+
+        count = next;
+        current_root = components[current_root];
+
+        levels.push(Level {
+            edges: std::mem::replace(&mut edges, contracted),
+            cheapest: cheapest_ids,
+            cycles,
+            cycle_of,
+        });
+    };
+
+    let mut targets = vec![usize::MAX; original.len()];
+
+    for level in levels.into_iter().rev() {
+        for &(_, to, _, id) in &level.edges {
+            targets[id] = to;
+        }
+
+        // every cycle is entered by exactly one chosen edge, which replaces the edge of the cycle
+        let mut entered = vec![usize::MAX; level.cycles.len()];
+        for &id in &chosen {
+            let cycle = level.cycle_of[targets[id]];
+            if cycle != usize::MAX {
+                entered[cycle] = targets[id];
+            }
+        }
+
+        for (cycle, &entry) in level.cycles.iter().zip(&entered) {
+            chosen.extend(
+                cycle
+                    .iter()
+                    .filter(|&&node| node != entry)
+                    .map(|&node| level.cheapest[node]),
+            );
+        }
+    }
+
+    let mut parents = Parents::new(graph);
+    let mut total_cost = C::default();
+
+    for id in chosen {
+        let (from, to, cost) = original[id];
+        parents.insert(node_ids[from], node_ids[to]);
+        total_cost += cost;
+    }
+
+    let tree = Tree { root, parents };
+
+    Ok(Mst {
+        tree,
+        cost: total_cost,
+    })
+}
+
+/// Returns the cycles formed by the cheapest entering edges and the cycle of every node
+fn find_cycles<C: Copy>(
+    edges: &Edges<C>,
+    cheapest: &[Option<(C, usize)>],
+    root: usize,
+) -> (Vec<Vec<usize>>, Vec<usize>) {
+    let parent = |node: usize| cheapest[node].map(|(_, index)| edges[index].0);
+
+    let mut cycles = Vec::new();
+    let mut cycle_of = vec![usize::MAX; cheapest.len()];
+    let mut visited = vec![usize::MAX; cheapest.len()];
+
+    for start in 0..cheapest.len() {
+        let mut node = start;
+
+        // walks along the parents until the root, a known node or a node of the current walk
+        while node != root && visited[node] == usize::MAX {
+            visited[node] = start;
+            let Some(next) = parent(node) else {
+                break;
+            };
+            node = next;
+        }
+
+        if node == root || visited[node] != start || cycle_of[node] != usize::MAX {
+            continue;
+        }
+
+        let Some(mut member) = parent(node) else {
+            continue;
+        };
+
+        let mut cycle = vec![node];
+        cycle_of[node] = cycles.len();
+
+        while member != node {
+            cycle_of[member] = cycles.len();
+            cycle.push(member);
+            member = parent(member).unwrap();
+        }
+
+        cycles.push(cycle);
+    }
+
+    (cycles, cycle_of)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::chu_liu_edmonds;
+    use crate::algorithms::kruskal;
+    use crate::test::{digraph, id, undigraph};
+    use crate::tree::Unreachable;
+    use grax_core::collections::{NodeCount, NodeIter};
+    use grax_impl::*;
+    use test::Bencher;
+
+    fn brute_force(edges: &[(usize, usize, f64)], count: usize, root: usize) -> f64 {
+        fn choose(
+            edges: &[(usize, usize, f64)],
+            parents: &mut Vec<Option<(usize, f64)>>,
+            node: usize,
+            root: usize,
+        ) -> f64 {
+            if node == parents.len() {
+                // every node has to reach the root along its parents
+                let valid = (0..parents.len()).all(|start| {
+                    let mut current = start;
+                    for _ in 0..parents.len() {
+                        match parents[current] {
+                            Some((parent, _)) => current = parent,
+                            None => break,
+                        }
+                    }
+                    current == root
+                });
+
+                return if valid {
+                    parents.iter().flatten().map(|(_, cost)| cost).sum()
+                } else {
+                    f64::MAX
+                };
+            }
+
+            if node == root {
+                return choose(edges, parents, node + 1, root);
+            }
+
+            let mut min = f64::MAX;
+            for &(from, to, cost) in edges {
+                if to == node && from != node {
+                    parents[node] = Some((from, cost));
+                    min = min.min(choose(edges, parents, node + 1, root));
+                    parents[node] = None;
+                }
+            }
+            min
+        }
+
+        choose(edges, &mut vec![None; count], 0, root)
+    }
+
+    #[bench]
+    fn chu_liu_edmonds_cycle_adj_list(b: &mut Bencher) {
+        // the cheapest entering edges of 1, 2 and 3 form a cycle, which is entered at 1
+        let graph = AdjGraph::<(), f64>::with_edges(
+            [
+                (0, 1, 10.0),
+                (0, 2, 12.0),
+                (0, 3, 15.0),
+                (1, 2, 1.0),
+                (2, 3, 1.0),
+                (3, 1, 1.0),
+            ],
+            4,
+        );
+
+        b.iter(|| {
+            let arborescence = chu_liu_edmonds(&graph, id(0)).unwrap();
+            let parents = &arborescence.tree.parents;
+
+            assert_eq!(arborescence.cost, 12.0);
+            assert_eq!(arborescence.tree.root, id(0));
+            assert_eq!(parents.parent(id(1)), Some(id(0)));
+            assert_eq!(parents.parent(id(2)), Some(id(1)));
+            assert_eq!(parents.parent(id(3)), Some(id(2)));
+            assert_eq!(parents.parent(id(0)), None);
+        })
+    }
+
+    #[bench]
+    fn chu_liu_edmonds_unreachable_adj_list(b: &mut Bencher) {
+        let graph = AdjGraph::<(), f64>::with_edges([(0, 1, 1.0), (2, 1, 1.0), (1, 3, 1.0)], 4);
+
+        b.iter(|| {
+            let result = chu_liu_edmonds(&graph, id(0));
+            assert_eq!(result.err(), Some(Unreachable(id(2))));
+        })
+    }
+
+    #[bench]
+    fn chu_liu_edmonds_brute_force_adj_list(b: &mut Bencher) {
+        // asymmetric costs with nested cycles
+        let edges = (0..7)
+            .flat_map(|from| {
+                (0..7)
+                    .filter(move |&to| to != from && (from * 5 + to * 3) % 4 != 0)
+                    .map(move |to| (from, to, ((from * 7 + to * 3) % 11) as f64))
+            })
+            .collect::<Vec<_>>();
+        let graph = AdjGraph::<(), f64>::with_edges(edges.iter().copied(), 7);
+
+        b.iter(|| {
+            for root in 0..7 {
+                let arborescence = chu_liu_edmonds(&graph, id(root)).unwrap();
+                let parents = &arborescence.tree.parents;
+                let cost = parents
+                    .edge_ids()
+                    .map(|edge_id| graph[edge_id])
+                    .sum::<f64>();
+
+                assert_eq!(arborescence.cost, brute_force(&edges, 7, root));
+                assert_eq!(cost, arborescence.cost);
+                assert_eq!(parents.edge_ids().count(), graph.node_count() - 1);
+            }
+        })
+    }
+
+    #[bench]
+    fn chu_liu_edmonds_undirected_kruskal_adj_list(b: &mut Bencher) {
+        // both directions of an undirected edge cost the same, so any spanning tree can be directed
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_20.txt").unwrap();
+        let expected = kruskal(&graph).unwrap().cost;
+        let root = graph.node_ids().nth(3).unwrap();
+
+        b.iter(|| {
+            let total = chu_liu_edmonds(&graph, root).unwrap().cost;
+            assert!((total - expected).abs() < 1e-9);
+        })
+    }
+
+    #[bench]
+    fn chu_liu_edmonds_undirected_kruskal_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _> = undigraph("../data/G_1_20.txt").unwrap();
+        let expected = kruskal(&graph).unwrap().cost;
+        let root = graph.node_ids().next().unwrap();
+
+        b.iter(|| {
+            let total = chu_liu_edmonds(&graph, root).unwrap().cost;
+            assert!((total - expected).abs() < 1e-9);
+        })
+    }
+
+    #[bench]
+    fn chu_liu_edmonds_wege_1_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Wege1.txt").unwrap();
+
+        b.iter(|| {
+            let arborescence = chu_liu_edmonds(&graph, id(2)).unwrap();
+            let parents = &arborescence.tree.parents;
+
+            assert_eq!(parents.edge_ids().count(), graph.node_count() - 1);
+        })
+    }
+}
@@ -0,0 +1,339 @@
+use super::{
+    McfSolver, empty_flow, remove_residual_edges, residual_network::ResidualNetwork, sum_cost_flow,
+};
+use crate::flow::FlowCostBundle;
+
+use grax_core::{collections::*, graph::NodeAttribute, index::Identifier};
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+/// Factor by which epsilon is divided after every refinement
+const ALPHA: u8 = 8;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CostScaling;
+
+impl<C, G> McfSolver<C, G> for CostScaling
+where
+    C: Default
+        + Add<C, Output = C>
+        + AddAssign<C>
+        + Sum
+        + Sub<C, Output = C>
+        + SubAssign<C>
+        + Mul<C, Output = C>
+        + Div<C, Output = C>
+        + Neg<Output = C>
+        + From<u8>
+        + Copy
+        + PartialOrd
+        + Debug,
+    G: EdgeCollection<EdgeWeight = FlowCostBundle<C>>
+        + NodeCollection<NodeWeight = C>
+        + NodeAttribute
+        + NodeIter
+        + EdgeIter
+        + EdgeIterMut
+        + IndexEdgeMut
+        + RemoveEdge,
+{
+    fn solve(graph: &mut G) -> Option<C> {
+        cost_scaling(graph)
+    }
+}
+
+/// Goldberg's cost scaling push-relabel algorithm.
+/// Every refinement turns an `alpha * epsilon` optimal flow into an `epsilon` optimal flow,
+/// where the node potentials certify the optimality.
+/// Costs are scaled by the node count, so that the result is optimal for integral costs.
+/// Feasibility is tracked by artificial arcs over an additional root.
+pub fn cost_scaling<C, G>(graph: &mut G) -> Option<C>
+where
+    C: Default
+        + Add<C, Output = C>
+        + AddAssign<C>
+        + Sum
+        + Sub<C, Output = C>
+        + SubAssign<C>
+        + Mul<C, Output = C>
+        + Div<C, Output = C>
+        + Neg<Output = C>
+        + From<u8>
+        + Copy
+        + PartialOrd
+        + Debug,
+    G: EdgeCollection<EdgeWeight = FlowCostBundle<C>>
+        + NodeCollection<NodeWeight = C>
+        + NodeAttribute
+        + NodeIter
+        + EdgeIter
+        + EdgeIterMut
+        + IndexEdgeMut
+        + RemoveEdge,
+{
+    let zero = C::default();
+    let one = C::from(1);
+    let alpha = C::from(ALPHA);
+    let abs = |value: C| if value < zero { -value } else { value };
+
+    let mut network = ResidualNetwork::from_graph(graph);
+    let node_count = network.node_count();
+    let real_arcs = network.heads.len();
+
+    let supply = network
+        .excess
+        .iter()
+        .filter(|&&excess| excess > zero)
+        .copied()
+        .sum::<C>();
+    let demand = network
+        .excess
+        .iter()
+        .filter(|&&excess| excess < zero)
+        .map(|&excess| -excess)
+        .sum::<C>();
+
+    // the excess could never be discharged otherwise
+    if supply != demand {
+        remove_residual_edges(graph);
+        empty_flow(graph);
+        return None;
+    }
+    let total_cost = network
+        .costs
+        .iter()
+        .step_by(2)
+        .map(|&cost| abs(cost))
+        .sum::<C>();
+
+    // exceeds the cost of every path over real arcs if there is any supply at all
+    let artificial_cost = total_cost + supply;
+    let root = network.add_node(zero);
+
+    for node in 0..node_count {
+        network.add_arc(node, root, supply, artificial_cost);
+        network.add_arc(root, node, supply, artificial_cost);
+    }
+
+    let scale = (0..=network.node_count()).fold(zero, |scale, _| scale + one);
+    let mut epsilon = zero;
+
+    for cost in network.costs.iter_mut() {
+        *cost = *cost * scale;
+        epsilon = if abs(*cost) > epsilon {
+            abs(*cost)
+        } else {
+            epsilon
+        };
+    }
+
+    let mut potentials = vec![zero; network.node_count()];
+
+    loop {
+        epsilon = epsilon / alpha;
+        if epsilon < one {
+            epsilon = one;
+        }
+
+        refine(&mut network, &mut potentials, epsilon);
+
+        if epsilon == one {
+            break;
+        }
+    }
+
+    let infeasible = (real_arcs..network.heads.len())
+        .step_by(2)
+        .any(|arc| network.flow(arc) > zero);
+
+    if infeasible || network.excess.iter().any(|&excess| excess != zero) {
+        remove_residual_edges(graph);
+        empty_flow(graph);
+        return None;
+    }
+
+    network.write_flow(graph);
+    remove_residual_edges(graph);
+
+    Some(sum_cost_flow(graph))
+}
+
+/// Saturates every arc with a negative reduced cost and
+/// removes the resulting excess by pushing along admissible arcs,
+/// which have a residual capacity and a negative reduced cost.
+fn refine<C, K>(network: &mut ResidualNetwork<C, K>, potentials: &mut [C], epsilon: C)
+where
+    C: Default
+        + Copy
+        + PartialOrd
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + AddAssign
+        + SubAssign
+        + Neg<Output = C>,
+    K: Identifier,
+{
+    let zero = C::default();
+
+    for arc in 0..network.heads.len() {
+        let residual = network.residuals[arc];
+
+        if residual > zero && network.reduced_cost(potentials, arc) < zero {
+            network.push(arc, residual);
+        }
+    }
+
+    let mut current = vec![0; network.node_count()];
+    let mut active = (0..network.node_count())
+        .filter(|&node| network.excess[node] > zero)
+        .collect::<VecDeque<_>>();
+
+    while let Some(node) = active.pop_front() {
+        while network.excess[node] > zero {
+            let Some(&arc) = network.adjacent[node].get(current[node]) else {
+                // lowers the potential just enough that an arc becomes admissible,
+                // its reduced cost c(v, w) + p(v) - p(w) turns -epsilon
+                potentials[node] = network.adjacent[node]
+                    .iter()
+                    .filter(|&&arc| network.residuals[arc] > zero)
+                    .map(|&arc| potentials[network.heads[arc]] - network.costs[arc])
+                    .fold(None, |max, value| match max {
+                        Some(max) if max >= value => Some(max),
+                        _ => Some(value),
+                    })
+                    .unwrap()
+                    - epsilon;
+                current[node] = 0;
+                continue;
+            };
+
+            let residual = network.residuals[arc];
+
+            if residual > zero && network.reduced_cost(potentials, arc) < zero {
+                let to = network.heads[arc];
+                let excess = network.excess[node];
+                let amount = if excess < residual { excess } else { residual };
+
+                if network.excess[to] <= zero && network.excess[to] + amount > zero {
+                    active.push_back(to);
+                }
+
+                network.push(arc, amount);
+            } else {
+                current[node] += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::CostScaling;
+    use crate::algorithms::{McfSolver, empty_flow};
+    use crate::test::bgraph;
+    use grax_impl::*;
+    use test::Bencher;
+
+    #[bench]
+    fn cost_scaling_kostenminimal_1_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal1.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 3.0);
+            empty_flow(&mut graph);
+        });
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_2_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal2.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 0.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_3_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal3.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_4_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal4.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_gross_1_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross1.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1537.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_gross_2_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross2.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1838.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_gross_3_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross3.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_1_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal1.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 3.0);
+            empty_flow(&mut graph);
+        });
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_gross_1_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal_gross1.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1537.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn cost_scaling_kostenminimal_gross_2_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal_gross2.txt").unwrap();
+        b.iter(|| {
+            let cost = CostScaling::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1838.0);
+            empty_flow(&mut graph);
+        })
+    }
+}
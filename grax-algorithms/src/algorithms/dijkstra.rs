@@ -1,3 +1,4 @@
+use super::ShortestPathFinder;
 use crate::{
     distances::Distances, parents::Parents, path::ShortestPath, tree::ShortestPathTree,
     weight::TotalOrd,
@@ -11,28 +12,40 @@ use orx_priority_queue::{DaryHeap, PriorityQueue};
 use std::fmt::Debug;
 use std::ops::Add;
 
-// #[derive(Clone, Copy)]
-// pub struct Dijkstra;
-
-// impl<C, G> ShortestPathFinder<C, G> for Dijkstra
-// where
-//     C: Default + Sortable + Copy + Add<C, Output = C> + Debug,
-//     G: EdgeIterAdjacent + NodeAttribute + NodeCount,
-//     G::EdgeWeight: Cost<C>,
-// {
-//     fn shortest_path(self, graph: &G, from: NodeId<G::Key>) -> ShortestPath<C, G> {
-//         dijkstra(graph, from)
-//     }
-
-//     fn shortest_path_to(
-//         self,
-//         graph: &G,
-//         from: NodeId<G::Key>,
-//         to: NodeId<G::Key>,
-//     ) -> ShortestPathTo<C, G> {
-//         dijkstra_to(graph, from, to)
-//     }
-// }
+#[derive(Clone, Copy)]
+pub struct Dijkstra;
+
+impl<C, G> ShortestPathFinder<C, G> for Dijkstra
+where
+    C: Default + TotalOrd + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    G: EdgeIterAdjacent + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
+{
+    fn shortest_path_tree_where<F>(
+        self,
+        graph: &G,
+        from: NodeId<G::Key>,
+        filter: F,
+    ) -> ShortestPathTree<C, G>
+    where
+        F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    {
+        dijkstra_where(graph, from, filter)
+    }
+
+    fn shortest_path_where<F>(
+        self,
+        graph: &G,
+        from: NodeId<G::Key>,
+        to: NodeId<G::Key>,
+        filter: F,
+    ) -> Option<ShortestPath<C, G>>
+    where
+        F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    {
+        dijkstra_to_where(graph, from, to, filter)
+    }
+}
 
 pub fn dijkstra_to<C, G>(
     graph: &G,
@@ -43,6 +56,21 @@ where
     C: Default + TotalOrd + Copy + Add<C, Output = C> + Debug + PartialOrd,
     G: EdgeIterAdjacent + NodeAttribute + NodeCount,
     G::EdgeWeight: Cost<C>,
+{
+    dijkstra_to_where(graph, from, to, |_| true)
+}
+
+pub fn dijkstra_to_where<C, F, G>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    to: NodeId<G::Key>,
+    filter: F,
+) -> Option<ShortestPath<C, G>>
+where
+    C: Default + TotalOrd + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    G: EdgeIterAdjacent + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
 {
     let mut priority_queue = DaryHeap::<_, _, 4>::with_capacity(graph.node_count());
     let mut distances = Distances::new(graph);
@@ -69,7 +97,11 @@ where
             continue;
         }
 
-        for EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(node) {
+        for edge @ EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(node) {
+            if !filter(edge) {
+                continue;
+            }
+
             let next = dist + *weight.cost();
             let to = edge_id.to();
 
@@ -93,6 +125,16 @@ where
     C: Default + TotalOrd + Copy + Add<C, Output = C> + Debug + PartialOrd,
     G: EdgeIterAdjacent + NodeAttribute + NodeCount,
     G::EdgeWeight: Cost<C>,
+{
+    dijkstra_where(graph, from, |_| true)
+}
+
+pub fn dijkstra_where<C, F, G>(graph: &G, from: NodeId<G::Key>, filter: F) -> ShortestPathTree<C, G>
+where
+    C: Default + TotalOrd + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+    G: EdgeIterAdjacent + NodeAttribute + NodeCount,
+    G::EdgeWeight: Cost<C>,
 {
     let mut priority_queue = DaryHeap::<_, _, 4>::with_capacity(graph.node_count());
     let mut distances = Distances::new(graph);
@@ -108,7 +150,11 @@ where
             continue;
         }
 
-        for EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(node) {
+        for edge @ EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(node) {
+            if !filter(edge) {
+                continue;
+            }
+
             let next = dist + *weight.cost();
             let to = edge_id.to();
 
@@ -135,8 +181,10 @@ where
 mod test {
     extern crate test;
 
-    use super::dijkstra_to;
+    use super::{Dijkstra, dijkstra_to};
+    use crate::algorithms::{BellmanFord, ShortestPathFinder};
     use crate::test::{digraph, id, undigraph};
+    use grax_core::edge::EdgeRef;
     use grax_impl::*;
     use test::Bencher;
 
@@ -191,4 +239,31 @@ mod test {
             assert_eq!(total as f32, 2.0)
         })
     }
+
+    #[bench]
+    fn dijkstra_g_1_2_di_where_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let filter = |edge: EdgeRef<usize, f64>| *edge.weight < 0.5;
+
+        b.iter(|| {
+            let total = Dijkstra
+                .shortest_path_where(&graph, id(0), id(1), filter)
+                .map(|path| path.distance);
+            let expected = BellmanFord
+                .shortest_path_where(&graph, id(0), id(1), filter)
+                .map(|path| path.distance);
+            assert_eq!(total, expected)
+        })
+    }
+
+    #[bench]
+    fn dijkstra_g_1_2_di_tree_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let tree = Dijkstra.shortest_path_tree(&graph, id(0));
+            let total = *tree.distances.distance(id(1)).unwrap();
+            assert_eq!(total as f32, 5.56283)
+        })
+    }
 }
@@ -0,0 +1,335 @@
+use super::{insert_residual_edges, remove_residual_edges};
+use crate::weight::TotalOrd;
+
+use grax_core::collections::{
+    EdgeIter, GetEdge, IndexEdge, IndexEdgeMut, InsertEdge, NodeIter, RemoveEdge,
+};
+use grax_core::edge::weight::*;
+use grax_core::graph::{EdgeAttribute, EdgeIterAdjacent, NodeAttribute};
+use grax_core::prelude::*;
+use std::collections::VecDeque;
+use std::{
+    fmt::Debug,
+    ops::{AddAssign, Sub, SubAssign},
+};
+
+/// Maximum flow using Dinic's algorithm.
+/// Every phase builds the level graph by breadth first search
+/// and saturates it with a blocking flow found by depth first search.
+pub fn dinic<C, G>(graph: &mut G, source: NodeId<G::Key>, sink: NodeId<G::Key>) -> C
+where
+    C: Default + PartialOrd + Copy + AddAssign + SubAssign + Sub<C, Output = C> + Debug + TotalOrd,
+    G: IndexEdge
+        + IndexEdgeMut
+        + EdgeAttribute
+        + NodeAttribute
+        + EdgeIterAdjacent
+        + InsertEdge
+        + RemoveEdge
+        + GetEdge
+        + EdgeIter
+        + NodeIter,
+    G::EdgeWeight: Flow<C> + Capacity<C> + Reverse,
+{
+    insert_residual_edges(graph);
+
+    let total_flow = _dinic(graph, source, sink);
+
+    remove_residual_edges(graph);
+
+    total_flow
+}
+
+pub(crate) fn _dinic<C, G>(graph: &mut G, source: NodeId<G::Key>, sink: NodeId<G::Key>) -> C
+where
+    C: Default + PartialOrd + Copy + AddAssign + SubAssign + Sub<C, Output = C> + Debug + TotalOrd,
+    G: IndexEdge + IndexEdgeMut + NodeAttribute + EdgeIterAdjacent + NodeIter,
+    G::EdgeWeight: Flow<C> + Capacity<C>,
+{
+    let mut total_flow = C::default();
+
+    // no path leaves the source before reaching the sink
+    if source == sink {
+        return total_flow;
+    }
+
+    let mut adjacent = graph.fixed_node_map(Vec::new());
+
+    for node in graph.node_ids() {
+        adjacent[node] = graph.adjacent_edge_ids(node).collect();
+    }
+
+    let admissible =
+        |graph: &G, levels: &G::FixedNodeMap<Option<usize>>, edge_id: EdgeId<G::Key>| {
+            graph[edge_id].residual_capacity() > C::default()
+                && levels[edge_id.from()].map(|level| level + 1) == levels[edge_id.to()]
+        };
+
+    loop {
+        let levels = level_graph(graph, &adjacent, source);
+
+        if levels[sink].is_none() {
+            break;
+        }
+
+        // current arc of every node, edges before it are saturated or lead to dead ends
+        let mut current = graph.fixed_node_map(0);
+        let mut path = Vec::new();
+
+        loop {
+            let node = path
+                .last()
+                .map_or(source, |edge_id: &EdgeId<G::Key>| edge_id.to());
+
+            if node == sink {
+                let bottleneck = path
+                    .iter()
+                    .map(|&edge_id| graph[edge_id].residual_capacity())
+                    .min_by(TotalOrd::total_ord)
+                    .unwrap();
+
+                total_flow += bottleneck;
+
+                for edge_id in path.drain(..) {
+                    *graph[edge_id].flow_mut() += bottleneck;
+                    *graph[edge_id.reverse()].flow_mut() -= bottleneck;
+                }
+                continue;
+            }
+
+            let next = adjacent[node][current[node]..]
+                .iter()
+                .position(|&edge_id| admissible(graph, &levels, edge_id));
+
+            match next {
+                Some(offset) => {
+                    current[node] += offset;
+                    path.push(adjacent[node][current[node]]);
+                }
+                None => {
+                    current[node] = adjacent[node].len();
+
+                    // retreat from the dead end
+                    match path.pop() {
+                        Some(edge_id) => current[edge_id.from()] += 1,
+                        None => break,
+                    }
+                }
+            }
+        }
+    }
+
+    total_flow
+}
+
+/// Distances from the source in the residual graph
+fn level_graph<C, G>(
+    graph: &G,
+    adjacent: &G::FixedNodeMap<Vec<EdgeId<G::Key>>>,
+    source: NodeId<G::Key>,
+) -> G::FixedNodeMap<Option<usize>>
+where
+    C: Default + PartialOrd + Copy + Sub<C, Output = C>,
+    G: IndexEdge + NodeAttribute,
+    G::EdgeWeight: Flow<C> + Capacity<C>,
+{
+    let mut levels = graph.fixed_node_map(None);
+    let mut queue = VecDeque::from([source]);
+    levels[source] = Some(0);
+
+    while let Some(node) = queue.pop_front() {
+        let level = levels[node].map(|level| level + 1);
+
+        for &edge_id in &adjacent[node] {
+            let to = edge_id.to();
+
+            if levels[to].is_none() && graph[edge_id].residual_capacity() > C::default() {
+                levels[to] = level;
+                queue.push_back(to);
+            }
+        }
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::dinic;
+    use crate::algorithms::{edmonds_karp, empty_flow, flow_adaptor};
+    use crate::test::{digraph, id};
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    // adj
+
+    #[bench]
+    fn dinic_g_1_2_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 0.75447);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_fluss_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 4.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_source_sink_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(0));
+            assert_eq!(total, 0.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_fluss2_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Fluss2.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 5.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_g_1_20_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_20.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            empty_flow(&mut graph);
+            let expected = edmonds_karp(&mut graph, id(0), id(7));
+            empty_flow(&mut graph);
+
+            assert_le!((total - expected).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn dinic_bipartite_unit_adj_list(b: &mut Bencher) {
+        // source 0, left 1 to 3, right 4 to 6, sink 7
+        let graph = AdjGraph::<(), f64, true>::with_edges(
+            [
+                (0, 1, 1.0),
+                (0, 2, 1.0),
+                (0, 3, 1.0),
+                (1, 4, 1.0),
+                (1, 5, 1.0),
+                (2, 4, 1.0),
+                (3, 5, 1.0),
+                (3, 6, 1.0),
+                (4, 7, 1.0),
+                (5, 7, 1.0),
+                (6, 7, 1.0),
+            ],
+            8,
+        );
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total, 3.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    // dense
+
+    #[bench]
+    fn dinic_g_1_2_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let mut graph: MatGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 0.75447);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_fluss_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: MatGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 4.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_fluss2_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/Fluss2.txt").unwrap();
+        let mut graph: MatGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 5.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    // csr
+
+    #[bench]
+    fn dinic_g_1_2_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let mut graph: CsrGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 0.75447);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_fluss_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: CsrGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 4.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn dinic_fluss2_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/Fluss2.txt").unwrap();
+        let mut graph: CsrGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 5.0);
+            empty_flow(&mut graph);
+        })
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{cycle::Cycle, distances::AllPairsDistances, parents::Parents};
+
+use grax_core::collections::NodeIter;
+use grax_core::edge::{weight::*, *};
+use grax_core::graph::{EdgeIterAdjacent, NodeAttribute};
+use std::fmt::Debug;
+use std::ops::Add;
+
+/// All pairs shortest paths in `O(n^3)`, best suited for dense graphs.
+/// Returns a cycle if a negative cycle is reachable.
+pub fn floyd_warshall<C, G>(graph: &G) -> Result<AllPairsDistances<C, G>, Cycle<G>>
+where
+    C: Default + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    G: NodeAttribute + EdgeIterAdjacent + NodeIter,
+    G::EdgeWeight: Cost<C>,
+{
+    let mut all_pairs = AllPairsDistances::new(graph);
+    let nodes = graph.node_ids().collect::<Vec<_>>();
+
+    for &from in &nodes {
+        all_pairs.distances[from][from] = Some(C::default());
+
+        for EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(from) {
+            let to = edge_id.to();
+            let cost = *weight.cost();
+
+            if all_pairs.distances[from][to].is_none_or(|prev| cost < prev) {
+                all_pairs.distances[from][to] = Some(cost);
+                all_pairs.parents[from][to] = Some(from);
+            }
+        }
+    }
+
+    for &via in &nodes {
+        let via_distances = all_pairs.distances[via].clone();
+        let via_parents = all_pairs.parents[via].clone();
+
+        for &from in &nodes {
+            let Some(to_via) = all_pairs.distances[from][via] else {
+                continue;
+            };
+
+            for &to in &nodes {
+                let Some(from_via) = via_distances[to] else {
+                    continue;
+                };
+                let next = to_via + from_via;
+
+                if all_pairs.distances[from][to].is_none_or(|prev| next < prev) {
+                    all_pairs.distances[from][to] = Some(next);
+                    all_pairs.parents[from][to] = via_parents[to];
+                }
+            }
+
+            if all_pairs.distances[from][from].is_some_and(|dist| dist < C::default()) {
+                let mut parents = Parents::new(graph);
+                parents.extend(
+                    nodes
+                        .iter()
+                        .filter_map(|&to| all_pairs.parents[from][to].map(|parent| (parent, to))),
+                );
+
+                return Err(Cycle::detect(graph, parents));
+            }
+        }
+    }
+
+    Ok(all_pairs)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::floyd_warshall;
+    use crate::algorithms::dijkstra;
+    use crate::test::{digraph, id, undigraph};
+    use grax_core::collections::NodeIter;
+    use grax_core::index::EdgeId;
+    use grax_impl::*;
+    use test::Bencher;
+
+    #[bench]
+    fn floyd_warshall_wege_1_di_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/Wege1.txt").unwrap();
+
+        b.iter(|| {
+            let all_pairs = floyd_warshall(&graph).unwrap();
+            let total = *all_pairs.distance(id(2), id(0)).unwrap();
+            assert_eq!(total as f32, 6.0);
+
+            let path = all_pairs.path(id(2), id(0)).unwrap();
+            assert_eq!(path.first(), Some(&id(2)));
+            assert_eq!(path.last(), Some(&id(0)));
+        })
+    }
+
+    #[bench]
+    fn floyd_warshall_wege_2_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Wege2.txt").unwrap();
+
+        b.iter(|| {
+            let all_pairs = floyd_warshall(&graph).unwrap();
+            let total = *all_pairs.distance(id(2), id(0)).unwrap();
+            assert_eq!(total as f32, 2.0);
+
+            let cost = all_pairs
+                .path(id(2), id(0))
+                .unwrap()
+                .windows(2)
+                .map(|pair| graph[EdgeId::new_unchecked(pair[0], pair[1])])
+                .sum::<f64>();
+            assert_eq!(cost as f32, 2.0);
+        })
+    }
+
+    #[bench]
+    fn floyd_warshall_wege_3_di_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/Wege3.txt").unwrap();
+
+        b.iter(|| {
+            let cycle = floyd_warshall(&graph).unwrap_err();
+            let cost = cycle
+                .iter_edges()
+                .map(|edge_id| graph[edge_id])
+                .sum::<f64>();
+            assert!(cost < 0.0);
+        })
+    }
+
+    #[bench]
+    fn floyd_warshall_k_12_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let all_pairs = floyd_warshall(&graph).unwrap();
+
+            for from in graph.node_ids() {
+                let tree = dijkstra(&graph, from);
+
+                for to in graph.node_ids() {
+                    assert_eq!(
+                        all_pairs.distance(from, to).map(|dist| *dist as f32),
+                        tree.distances.distance(to).map(|dist| *dist as f32)
+                    );
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,327 @@
+use super::{_cost_matrix, Tour, TspSolver};
+use crate::cycle::TspCycle;
+
+use grax_core::collections::{GetEdge, NodeIter};
+use grax_core::edge::weight::*;
+use grax_core::graph::NodeAttribute;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, SubAssign};
+use std::time::{Duration, Instant};
+
+/// Genetic algorithm over a population of routes,
+/// where the parents are selected by tournaments, recombined by order crossover
+/// and the children mutated by reversing a random part of their route.
+/// The best route always survives and the nearest neighbor route is part of the first population.
+#[derive(Debug, Clone, Copy)]
+pub struct Genetic {
+    /// Seed of the random number generator, the same seed results in the same route
+    pub seed: u64,
+    /// Number of routes in every generation
+    pub population: usize,
+    /// Number of generations
+    pub generations: usize,
+    /// Stops before the last generation once the time is exceeded
+    pub time_limit: Option<Duration>,
+    /// Probability of a child to be mutated, rates outside of `[0, 1]` act like the closest bound
+    pub mutation_rate: f64,
+}
+
+impl Default for Genetic {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            population: 100,
+            generations: 1_000,
+            time_limit: None,
+            mutation_rate: 0.2,
+        }
+    }
+}
+
+impl Genetic {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_generations(mut self, generations: usize) -> Self {
+        self.generations = generations;
+        self
+    }
+
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+}
+
+impl<C, G> TspSolver<C, G> for Genetic
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + AddAssign + SubAssign + Debug,
+    G: NodeAttribute + NodeIter + GetEdge,
+    G::EdgeWeight: Cost<C>,
+{
+    fn solve(graph: &G) -> Option<TspCycle<C, G>> {
+        genetic(graph, Self::default())
+    }
+}
+
+/// Routes using missing edges are ranked behind every other route.
+/// Returns none if no route of the last population exists in the graph.
+pub fn genetic<C, G>(graph: &G, config: Genetic) -> Option<TspCycle<C, G>>
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + AddAssign + SubAssign + Debug,
+    G: NodeAttribute + NodeIter + GetEdge,
+    G::EdgeWeight: Cost<C>,
+{
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+
+    if node_ids.is_empty() {
+        return None;
+    }
+
+    let costs = _cost_matrix(graph, &node_ids);
+    let len = node_ids.len();
+    let population_size = config.population.max(2);
+    let mut rng = StdRng::seed_from_u64(config.seed);
+
+    let mut population = Vec::with_capacity(population_size);
+
+    if let Some(tour) = Tour::nearest_neighbor(costs.clone()) {
+        population.push(tour.order().to_vec());
+    }
+
+    while population.len() < population_size {
+        let mut order = (0..len).collect::<Vec<_>>();
+        order.shuffle(&mut rng);
+        population.push(order);
+    }
+
+    let mut population = population
+        .into_iter()
+        .map(|order| {
+            let cost = route_cost(&costs, &order);
+            (order, cost)
+        })
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+
+    for _ in 0..config.generations {
+        if config
+            .time_limit
+            .is_some_and(|limit| start.elapsed() >= limit)
+        {
+            break;
+        }
+
+        let elite = best(&population).clone();
+        let mut children = Vec::with_capacity(population_size);
+        children.push(elite);
+
+        while children.len() < population_size {
+            let first = &tournament(&population, &mut rng).0;
+            let second = &tournament(&population, &mut rng).0;
+            let mut child = order_crossover(first, second, &mut rng);
+
+            if rng.r#gen::<f64>() < config.mutation_rate {
+                let from = rng.gen_range(0..len);
+                let to = rng.gen_range(from..len);
+                child[from..=to].reverse();
+            }
+
+            let cost = route_cost(&costs, &child);
+            children.push((child, cost));
+        }
+
+        population = children;
+    }
+
+    let (order, cost) = best(&population).clone();
+    let cost = cost?;
+
+    Some(Tour::with_order(costs, order).into_route(graph, &node_ids, cost))
+}
+
+fn route_cost<C>(costs: &[Vec<Option<C>>], order: &[usize]) -> Option<C>
+where
+    C: Default + Copy + AddAssign,
+{
+    let mut total = C::default();
+
+    for (&from, &to) in order.iter().zip(order.iter().cycle().skip(1)) {
+        total += costs[from][to]?;
+    }
+
+    Some(total)
+}
+
+fn is_better<C: PartialOrd>(first: &Option<C>, second: &Option<C>) -> bool {
+    match (first, second) {
+        (Some(first), Some(second)) => first < second,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+fn best<C: PartialOrd>(population: &[(Vec<usize>, Option<C>)]) -> &(Vec<usize>, Option<C>) {
+    population
+        .iter()
+        .reduce(|best, current| {
+            if is_better(&current.1, &best.1) {
+                current
+            } else {
+                best
+            }
+        })
+        .unwrap()
+}
+
+/// Returns the best of three randomly chosen routes
+fn tournament<'a, C: PartialOrd>(
+    population: &'a [(Vec<usize>, Option<C>)],
+    rng: &mut StdRng,
+) -> &'a (Vec<usize>, Option<C>) {
+    (0..3)
+        .map(|_| &population[rng.gen_range(0..population.len())])
+        .reduce(|best, current| {
+            if is_better(&current.1, &best.1) {
+                current
+            } else {
+                best
+            }
+        })
+        .unwrap()
+}
+
+/// Copies a random part of the first parent into the child
+/// and fills the remaining positions with the missing nodes in the order of the second parent
+fn order_crossover(first: &[usize], second: &[usize], rng: &mut StdRng) -> Vec<usize> {
+    let len = first.len();
+    let from = rng.gen_range(0..len);
+    let to = rng.gen_range(from..len);
+
+    let mut child = vec![usize::MAX; len];
+    let mut used = vec![false; len];
+
+    for position in from..=to {
+        child[position] = first[position];
+        used[first[position]] = true;
+    }
+
+    let mut position = (to + 1) % len;
+
+    for offset in 0..len {
+        let node = second[(to + 1 + offset) % len];
+
+        if !used[node] {
+            child[position] = node;
+            used[node] = true;
+            position = (position + 1) % len;
+        }
+    }
+
+    child
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::{Genetic, genetic, order_crossover};
+    use crate::algorithms::{LocalSearch, TspImprover, nearest_neighbor};
+    use crate::cycle::TspCycle;
+    use crate::test::undigraph;
+    use grax_core::collections::NodeCount;
+    use grax_impl::*;
+    use more_asserts::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::time::Duration;
+    use test::Bencher;
+
+    fn assert_route(graph: &AdjGraph<(), f64>, route: &TspCycle<f64, AdjGraph<(), f64>>) {
+        let cost = route
+            .cycle
+            .iter_edges()
+            .map(|edge_id| graph[edge_id])
+            .sum::<f64>();
+
+        assert_eq!(route.cycle.iter().count(), graph.node_count());
+        assert_le!((cost - route.cost).abs(), 1e-9);
+    }
+
+    #[bench]
+    fn order_crossover_permutation(b: &mut Bencher) {
+        let first = (0..20).collect::<Vec<_>>();
+        let second = (0..20).rev().collect::<Vec<_>>();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        b.iter(|| {
+            let mut child = order_crossover(&first, &second, &mut rng);
+            child.sort();
+
+            assert_eq!(child, first);
+        })
+    }
+
+    #[bench]
+    fn genetic_k_12_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let route = genetic(&graph, Genetic::new(42)).unwrap();
+
+            assert_route(&graph, &route);
+            assert_le!(route.cost, 45.19 * 1.2);
+            assert_ge!(route.cost, 45.19 - 1e-9);
+        })
+    }
+
+    #[bench]
+    fn genetic_k_50_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_50.txt").unwrap();
+        let route = nearest_neighbor(&graph).unwrap();
+        let local_optimum = LocalSearch::default().improve(&graph, route).cost;
+
+        b.iter(|| {
+            let route = genetic(&graph, Genetic::new(42).with_generations(200)).unwrap();
+
+            // the nearest neighbor route is about 4% more expensive than the local optimum
+            assert_route(&graph, &route);
+            assert_le!(route.cost, local_optimum * 1.01);
+        })
+    }
+
+    #[bench]
+    fn genetic_seed_k_70_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_70.txt").unwrap();
+        let config = Genetic::new(7).with_generations(100);
+
+        b.iter(|| {
+            let first = genetic(&graph, config).unwrap();
+            let second = genetic(&graph, config).unwrap();
+
+            assert_eq!(first.cost, second.cost);
+            assert_eq!(first.cycle, second.cycle);
+        })
+    }
+
+    #[bench]
+    fn genetic_time_limit_k_100_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_100.txt").unwrap();
+        let config = Genetic::new(3)
+            .with_generations(usize::MAX)
+            .with_time_limit(Duration::from_millis(50));
+
+        b.iter(|| {
+            // only the time limit ends the run, the cost depends on the speed of the machine
+            let route = genetic(&graph, config).unwrap();
+            assert_route(&graph, &route);
+        })
+    }
+}
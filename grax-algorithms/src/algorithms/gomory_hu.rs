@@ -0,0 +1,170 @@
+use super::empty_flow;
+use crate::{
+    cut::MinCut,
+    parents::Parents,
+    tree::{GomoryHuTree, Tree},
+};
+
+use grax_core::collections::{EdgeIterMut, IndexEdge, NodeIter};
+use grax_core::edge::weight::*;
+use grax_core::graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, NodeAttribute};
+use grax_core::index::NodeId;
+use std::fmt::Debug;
+use std::ops::{AddAssign, Sub};
+
+/// Gusfield's construction of the gomory-hu tree of an undirected capacity graph
+/// with `n - 1` maximum flow computations of the given algorithm on the unmodified graph.
+/// Returns none if the graph is empty.
+pub fn gomory_hu<C, G, F>(graph: &mut G, mut max_flow: F) -> Option<GomoryHuTree<C, G>>
+where
+    C: Default + PartialOrd + Copy + Sub<C, Output = C> + AddAssign + Debug,
+    G: NodeAttribute
+        + NodeIter
+        + EdgeIterMut
+        + EdgeIterAdjacent
+        + EdgeIterAdjacentInbound
+        + IndexEdge,
+    G::EdgeWeight: Flow<C> + Capacity<C> + Reverse,
+    F: FnMut(&mut G, NodeId<G::Key>, NodeId<G::Key>) -> C,
+{
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+    let root = *node_ids.first()?;
+
+    let mut parents = Parents::new(graph);
+    let mut cuts = graph.fixed_node_map(C::default());
+
+    for &node in &node_ids[1..] {
+        parents.insert(root, node);
+    }
+
+    for (index, &source) in node_ids.iter().enumerate().skip(1) {
+        let sink = parents.parent(source).unwrap();
+
+        let flow = max_flow(graph, source, sink);
+        let cut = MinCut::<C, G>::from_flow(graph, source);
+        empty_flow(graph);
+
+        cuts[source] = flow;
+
+        for &node in &node_ids[index + 1..] {
+            if cut.is_source_side(node) && parents.parent(node) == Some(sink) {
+                parents.insert(source, node);
+            }
+        }
+
+        // keeps the tree a cut tree instead of only a flow equivalent tree
+        if let Some(parent) = parents.parent(sink)
+            && cut.is_source_side(parent)
+        {
+            parents.insert(parent, source);
+            parents.insert(source, sink);
+            cuts[source] = cuts[sink];
+            cuts[sink] = flow;
+        }
+    }
+
+    Some(GomoryHuTree {
+        tree: Tree { root, parents },
+        cuts,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::gomory_hu;
+    use crate::algorithms::{dinic, edmonds_karp, empty_flow, flow_adaptor, stoer_wagner};
+    use crate::test::id;
+    use grax_impl::*;
+    use test::Bencher;
+
+    fn gomory_hu_example() -> AdjGraph<(), f64> {
+        let edges = [
+            (0, 1, 1.0),
+            (0, 2, 7.0),
+            (1, 2, 1.0),
+            (1, 3, 3.0),
+            (1, 4, 2.0),
+            (2, 4, 4.0),
+            (3, 4, 1.0),
+            (3, 5, 6.0),
+            (4, 5, 2.0),
+        ];
+
+        AdjGraph::<(), f64>::with_edges(
+            edges
+                .into_iter()
+                .flat_map(|(from, to, weight)| [(from, to, weight), (to, from, weight)]),
+            6,
+        )
+    }
+
+    #[bench]
+    fn gomory_hu_example_dinic_adj_list(b: &mut Bencher) {
+        let mut network: AdjGraph<_, _> = flow_adaptor(gomory_hu_example());
+
+        b.iter(|| {
+            let tree = gomory_hu(&mut network.clone(), dinic).unwrap();
+
+            for from in 0..6 {
+                for to in 0..6 {
+                    if from == to {
+                        assert_eq!(tree.min_cut(id(from), id(to)), None);
+                        continue;
+                    }
+
+                    let flow = dinic(&mut network, id(from), id(to));
+                    empty_flow(&mut network);
+
+                    assert_eq!(tree.min_cut(id(from), id(to)), Some(flow));
+                }
+            }
+        })
+    }
+
+    #[bench]
+    fn gomory_hu_example_edmonds_karp_csr_mat(b: &mut Bencher) {
+        let graph = CsrGraph::<(), f64>::with_edges(
+            [
+                (0, 1, 1.0),
+                (1, 0, 1.0),
+                (1, 2, 2.0),
+                (2, 1, 2.0),
+                (0, 2, 3.0),
+                (2, 0, 3.0),
+            ],
+            3,
+        );
+        let mut network: CsrGraph<_, _> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let tree = gomory_hu(&mut network, edmonds_karp).unwrap();
+
+            assert_eq!(tree.min_cut(id(0), id(1)), Some(3.0));
+            assert_eq!(tree.min_cut(id(0), id(2)), Some(4.0));
+            assert_eq!(tree.min_cut(id(1), id(2)), Some(3.0));
+        })
+    }
+
+    #[bench]
+    fn gomory_hu_example_stoer_wagner(b: &mut Bencher) {
+        let graph = gomory_hu_example();
+        let network: AdjGraph<_, _> = flow_adaptor(graph.clone());
+
+        b.iter(|| {
+            let tree = gomory_hu(&mut network.clone(), dinic).unwrap();
+            let global = stoer_wagner(&graph).unwrap();
+
+            // the global minimum cut is the smallest cut in the tree
+            let min = tree
+                .tree
+                .parents
+                .edge_ids()
+                .map(|edge_id| tree.cuts[edge_id.to()])
+                .fold(f64::MAX, f64::min);
+
+            assert_eq!(global.capacity, min);
+        })
+    }
+}
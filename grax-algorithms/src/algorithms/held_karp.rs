@@ -0,0 +1,300 @@
+use super::TspSolver;
+use crate::{
+    cycle::{Cycle, TspCycle},
+    parents::Parents,
+    weight::{Bounded, TotalOrd},
+};
+
+use grax_core::collections::{GetEdge, NodeIter};
+use grax_core::edge::weight::*;
+use grax_core::graph::NodeAttribute;
+use grax_core::index::EdgeId;
+use std::ops::Add;
+
+/// Largest number of nodes solved by `held_karp`, whose tables already take about 3 GiB for `f64` costs
+pub const HELD_KARP_MAX_NODES: usize = 25;
+
+#[derive(Debug, Clone, Copy)]
+pub struct HeldKarp;
+
+impl<C, G> TspSolver<C, G> for HeldKarp
+where
+    C: Default + Bounded + PartialOrd + Add<C, Output = C> + Copy + TotalOrd,
+    G: NodeIter + GetEdge + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    fn solve(graph: &G) -> Option<TspCycle<C, G>> {
+        held_karp(graph)
+    }
+}
+
+/// Held-Karp dynamic programming over the subsets of nodes in `O(n² 2ⁿ)` time and `O(n 2ⁿ)` memory,
+/// which stores the cheapest path from the first node through every subset for each last node of the subset.
+/// Missing edges are never used, so none is returned if the graph has no hamiltonian cycle.
+/// None is also returned for more than `HELD_KARP_MAX_NODES` nodes, while a single node is a route of zero cost.
+pub fn held_karp<C, G>(graph: &G) -> Option<TspCycle<C, G>>
+where
+    C: Default + Bounded + PartialOrd + Add<C, Output = C> + Copy + TotalOrd,
+    G: NodeIter + GetEdge + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+    let start = *node_ids.first()?;
+
+    if node_ids.len() > HELD_KARP_MAX_NODES {
+        return None;
+    }
+
+    if node_ids.len() == 1 {
+        let mut route = Parents::new(graph);
+        route.insert(start, start);
+
+        return Some(TspCycle {
+            cost: C::default(),
+            cycle: Cycle {
+                member: start,
+                parents: route,
+            },
+        });
+    }
+
+    let cost = |from: usize, to: usize| {
+        graph
+            .edge(EdgeId::new_unchecked(node_ids[from], node_ids[to]))
+            .map(|edge| *edge.weight.cost())
+    };
+
+    // the subsets exclude the start node, so bit i stands for the node i + 1
+    let others = node_ids.len() - 1;
+    let full = (1 << others) - 1;
+    let mut costs = vec![C::MAX; (1 << others) * others];
+    let mut parents = vec![0_u8; (1 << others) * others];
+
+    for last in 0..others {
+        if let Some(cost) = cost(0, last + 1) {
+            costs[(1 << last) * others + last] = cost;
+        }
+    }
+
+    for subset in 1..=full {
+        for last in 0..others {
+            let current = costs[subset * others + last];
+
+            if subset & (1 << last) == 0 || current == C::MAX {
+                continue;
+            }
+
+            for next in 0..others {
+                if subset & (1 << next) != 0 {
+                    continue;
+                }
+
+                let Some(cost) = cost(last + 1, next + 1) else {
+                    continue;
+                };
+
+                let index = (subset | (1 << next)) * others + next;
+                let total = current + cost;
+
+                if total < costs[index] {
+                    costs[index] = total;
+                    parents[index] = last as u8;
+                }
+            }
+        }
+    }
+
+    let (total, mut last) = (0..others)
+        .filter(|&last| costs[full * others + last] != C::MAX)
+        .filter_map(|last| cost(last + 1, 0).map(|cost| (costs[full * others + last] + cost, last)))
+        .min_by(|a, b| a.0.total_ord(&b.0))?;
+
+    let mut route = Parents::new(graph);
+    let mut subset = full;
+
+    route.insert(node_ids[last + 1], start);
+
+    while subset != 1 << last {
+        let parent = parents[subset * others + last] as usize;
+
+        route.insert(node_ids[parent + 1], node_ids[last + 1]);
+        subset &= !(1 << last);
+        last = parent;
+    }
+
+    route.insert(start, node_ids[last + 1]);
+
+    Some(TspCycle {
+        cost: total,
+        cycle: Cycle {
+            member: start,
+            parents: route,
+        },
+    })
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::{HELD_KARP_MAX_NODES, held_karp};
+    use crate::test::undigraph;
+    use grax_core::collections::NodeCount;
+    use grax_impl::*;
+    use more_asserts::*;
+    use test::Bencher;
+
+    #[bench]
+    fn held_karp_k_10_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10.txt").unwrap();
+
+        b.iter(|| {
+            let route = held_karp(&graph).unwrap();
+
+            assert_eq!(route.cycle.iter().count(), graph.node_count());
+            assert_le!((route.cost - 38.41).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn held_karp_k_10e_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10e.txt").unwrap();
+
+        b.iter(|| {
+            let total = held_karp(&graph).unwrap().cost;
+            assert_le!((total - 27.26).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn held_karp_k_12_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let total = held_karp(&graph).unwrap().cost;
+            assert_le!((total - 45.19).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn held_karp_k_12e_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12e.txt").unwrap();
+
+        b.iter(|| {
+            let total = held_karp(&graph).unwrap().cost;
+            assert_le!((total - 36.13).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn held_karp_k_12_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let total = held_karp(&graph).unwrap().cost;
+            assert_le!((total - 45.19).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn held_karp_single_node_adj_list(b: &mut Bencher) {
+        let graph = AdjGraph::<(), f64>::with_edges([], 1);
+
+        b.iter(|| {
+            let route = held_karp(&graph).unwrap();
+
+            assert_eq!(route.cost, 0.0);
+            assert_eq!(
+                route.cycle.iter().collect::<Vec<_>>(),
+                vec![route.cycle.member]
+            );
+        })
+    }
+
+    #[bench]
+    fn held_karp_too_large_adj_list(b: &mut Bencher) {
+        let count = HELD_KARP_MAX_NODES + 1;
+        let edges = (0..count).flat_map(|from| {
+            (0..count)
+                .filter(move |&to| to != from)
+                .map(move |to| (from, to, 1.0))
+        });
+        let graph = AdjGraph::<(), f64>::with_edges(edges, count);
+
+        b.iter(|| {
+            assert!(held_karp(&graph).is_none());
+        })
+    }
+
+    fn permute(last: usize, visited: &mut [bool], count: usize) -> f64 {
+        let cost = |from: usize, to: usize| ((from * 7 + to * 3) % 11) as f64;
+
+        if count == visited.len() {
+            return cost(last, 0);
+        }
+
+        let mut min = f64::MAX;
+        for next in 1..visited.len() {
+            if !visited[next] {
+                visited[next] = true;
+                min = min.min(cost(last, next) + permute(next, visited, count + 1));
+                visited[next] = false;
+            }
+        }
+        min
+    }
+
+    #[bench]
+    fn held_karp_directed_brute_force_adj_list(b: &mut Bencher) {
+        // asymmetric costs, where the route has to follow the direction of the edges
+        let edges = (0..8).flat_map(|from| {
+            (0..8)
+                .filter(move |&to| to != from)
+                .map(move |to| (from, to, ((from * 7 + to * 3) % 11) as f64))
+        });
+        let graph = AdjGraph::<(), f64>::with_edges(edges, 8);
+        let expected = permute(0, &mut [false; 8], 1);
+
+        b.iter(|| {
+            let route = held_karp(&graph).unwrap();
+            let cost = route
+                .cycle
+                .iter_edges()
+                .map(|edge_id| graph[edge_id])
+                .sum::<f64>();
+
+            assert_eq!(route.cost, expected);
+            assert_eq!(cost, expected);
+        })
+    }
+
+    #[bench]
+    fn held_karp_no_cycle_adj_list(b: &mut Bencher) {
+        // a star has no hamiltonian cycle
+        let graph = AdjGraph::<(), f64>::with_edges(
+            (1..5).flat_map(|leaf| [(0, leaf, 1.0), (leaf, 0, 1.0)]),
+            5,
+        );
+
+        b.iter(|| {
+            assert!(held_karp(&graph).is_none());
+        })
+    }
+
+    #[cfg(feature = "extensive")]
+    #[bench]
+    fn held_karp_k_15_adj_list(b: &mut Bencher) {
+        use crate::algorithms::{LocalSearch, TspImprover, christofides};
+
+        let graph: AdjGraph<_, _> = undigraph("../data/K_15.txt").unwrap();
+        let route = christofides(&graph).unwrap();
+        let heuristic = LocalSearch::default()
+            .with_lin_kernighan(8)
+            .improve(&graph, route)
+            .cost;
+
+        b.iter(|| {
+            let total = held_karp(&graph).unwrap().cost;
+            assert_le!(total, heuristic);
+        })
+    }
+}
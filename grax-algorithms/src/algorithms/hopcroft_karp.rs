@@ -0,0 +1,327 @@
+use crate::matching::Matching;
+
+use grax_core::collections::{NodeIter, VisitNodeMap};
+use grax_core::graph::{EdgeIterAdjacent, NodeAttribute};
+use grax_core::index::{EdgeId, NodeId};
+use std::collections::VecDeque;
+
+/// Hopcroft-Karp maximum matching of a bipartite graph,
+/// which augments along a maximal set of shortest disjoint paths every phase.
+/// The partition decides which nodes are on the left side,
+/// for example `|node| partition[node]` or `|node| coloring[node] == 1` with the coloring of `bfs_bipartite`.
+/// Edges between nodes of the same side are ignored.
+pub fn hopcroft_karp<G, F>(graph: &G, is_left: F) -> Matching<G>
+where
+    G: NodeAttribute + NodeIter + EdgeIterAdjacent,
+    F: Fn(NodeId<G::Key>) -> bool,
+{
+    let left = graph
+        .node_ids()
+        .filter(|&node| is_left(node))
+        .collect::<Vec<_>>();
+    let mut mates = graph.fixed_node_map(None);
+
+    loop {
+        let Some(mut layers) = layers(graph, &left, &mates, &is_left) else {
+            break;
+        };
+
+        for &node in &left {
+            if mates[node].is_none() {
+                augment(graph, node, &mut layers, &mut mates, &is_left);
+            }
+        }
+    }
+
+    let edge_ids = left
+        .iter()
+        .filter_map(|&node| mates[node].map(|mate| EdgeId::new_unchecked(node, mate)))
+        .collect();
+
+    Matching { mates, edge_ids }
+}
+
+/// Minimum vertex cover of a bipartite graph from a maximum matching by kőnig's theorem,
+/// which certifies the maximality as both have the same size.
+/// Nodes reachable from unmatched left nodes by alternating paths are collected,
+/// the cover consists of the unreachable left nodes and the reachable right nodes.
+pub fn konig_vertex_cover<G, F>(
+    graph: &G,
+    matching: &Matching<G>,
+    is_left: F,
+) -> G::FixedNodeMap<bool>
+where
+    G: NodeAttribute + NodeIter + EdgeIterAdjacent,
+    F: Fn(NodeId<G::Key>) -> bool,
+{
+    let mut reachable = graph.visit_node_map();
+    let mut queue = graph
+        .node_ids()
+        .filter(|&node| is_left(node) && !matching.is_matched(node))
+        .collect::<VecDeque<_>>();
+
+    for &node in &queue {
+        reachable.visit(node);
+    }
+
+    while let Some(from) = queue.pop_front() {
+        for edge_id in graph.adjacent_edge_ids(from) {
+            let to = edge_id.to();
+
+            if is_left(to) || reachable.is_visited(to) {
+                continue;
+            }
+            reachable.visit(to);
+
+            if let Some(mate) = matching.mate(to)
+                && !reachable.is_visited(mate)
+            {
+                reachable.visit(mate);
+                queue.push_back(mate);
+            }
+        }
+    }
+
+    let mut cover = graph.visit_node_map();
+
+    for node in graph.node_ids() {
+        if is_left(node) != reachable.is_visited(node) {
+            cover.visit(node);
+        }
+    }
+
+    cover
+}
+
+/// Breadth first search from every unmatched left node, alternating between unmatched and matched edges.
+/// Stops after the first layer with an unmatched right node, as only the shortest augmenting paths are used.
+/// Returns the layer of every left node or none if no augmenting path exists.
+fn layers<G, F>(
+    graph: &G,
+    left: &[NodeId<G::Key>],
+    mates: &G::FixedNodeMap<Option<NodeId<G::Key>>>,
+    is_left: &F,
+) -> Option<G::FixedNodeMap<Option<usize>>>
+where
+    G: NodeAttribute + EdgeIterAdjacent,
+    F: Fn(NodeId<G::Key>) -> bool,
+{
+    let mut layers = graph.fixed_node_map(None);
+    let mut queue = VecDeque::new();
+    let mut limit = None;
+
+    for &node in left {
+        if mates[node].is_none() {
+            layers[node] = Some(0);
+            queue.push_back(node);
+        }
+    }
+
+    while let Some(from) = queue.pop_front() {
+        let layer = layers[from].unwrap();
+
+        if limit.is_some_and(|limit| layer > limit) {
+            layers[from] = None;
+            for node in queue.drain(..) {
+                layers[node] = None;
+            }
+            break;
+        }
+
+        for edge_id in graph.adjacent_edge_ids(from) {
+            let to = edge_id.to();
+
+            if is_left(to) {
+                continue;
+            }
+
+            match mates[to] {
+                None => limit = Some(layer),
+                Some(mate) if layers[mate].is_none() && limit.is_none() => {
+                    layers[mate] = Some(layer + 1);
+                    queue.push_back(mate);
+                }
+                Some(_) => (),
+            }
+        }
+    }
+
+    limit.map(|_| layers)
+}
+
+/// Depth first search along the layers, which flips the edges of the first augmenting path.
+/// Left nodes without a path are removed from the layers.
+fn augment<G, F>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    layers: &mut G::FixedNodeMap<Option<usize>>,
+    mates: &mut G::FixedNodeMap<Option<NodeId<G::Key>>>,
+    is_left: &F,
+) -> bool
+where
+    G: NodeAttribute + EdgeIterAdjacent,
+    F: Fn(NodeId<G::Key>) -> bool,
+{
+    // every left node of the path with the right node it was reached by and its remaining edges
+    let mut stack = vec![(from, None, graph.adjacent_edge_ids(from))];
+
+    while let Some((node, _, edge_ids)) = stack.last_mut() {
+        let node = *node;
+
+        let Some(edge_id) = edge_ids.next() else {
+            layers[node] = None;
+            stack.pop();
+            continue;
+        };
+
+        let to = edge_id.to();
+
+        if is_left(to) {
+            continue;
+        }
+
+        match mates[to] {
+            None => {
+                let mut right = to;
+
+                while let Some((node, via, _)) = stack.pop() {
+                    mates[node] = Some(right);
+                    mates[right] = Some(node);
+
+                    if let Some(via) = via {
+                        right = via;
+                    }
+                }
+
+                return true;
+            }
+            Some(mate) if layers[mate] == layers[node].map(|layer| layer + 1) => {
+                stack.push((mate, Some(to), graph.adjacent_edge_ids(mate)));
+            }
+            Some(_) => (),
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::{hopcroft_karp, konig_vertex_cover};
+    use crate::algorithms::bfs_bipartite;
+    use crate::test::id;
+    use grax_core::collections::{EdgeIter, NodeIter, VisitNodeMap};
+    use grax_core::edge::EdgeRef;
+    use grax_core::graph::NodeAttribute;
+    use grax_impl::*;
+    use test::Bencher;
+
+    fn grid(width: usize, height: usize) -> Vec<(usize, usize, ())> {
+        let mut edges = Vec::new();
+
+        for row in 0..height {
+            for column in 0..width {
+                let node = row * width + column;
+
+                if column + 1 < width {
+                    edges.extend([(node, node + 1, ()), (node + 1, node, ())]);
+                }
+                if row + 1 < height {
+                    edges.extend([(node, node + width, ()), (node + width, node, ())]);
+                }
+            }
+        }
+
+        edges
+    }
+
+    #[bench]
+    fn hopcroft_karp_unit_adj_list(b: &mut Bencher) {
+        // left 0 to 2, right 3 to 5
+        let edges = [(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)];
+        let graph = AdjGraph::<(), ()>::with_edges(
+            edges
+                .into_iter()
+                .flat_map(|(from, to)| [(from, to, ()), (to, from, ())]),
+            6,
+        );
+
+        b.iter(|| {
+            let matching = hopcroft_karp(&graph, |node| node < id(3));
+            assert_eq!(matching.len(), 3);
+            assert!(matching.is_perfect());
+            assert_eq!(matching.mate(id(1)), Some(id(3)));
+
+            for edge_id in &matching.edge_ids {
+                assert_eq!(matching.mate(edge_id.to()), Some(edge_id.from()));
+            }
+        })
+    }
+
+    #[bench]
+    fn hopcroft_karp_unbalanced_adj_list(b: &mut Bencher) {
+        // left 0 to 3 all compete for the right nodes 4 and 5
+        let edges = [(0, 4), (1, 4), (2, 4), (2, 5), (3, 4)];
+        let graph = AdjGraph::<(), ()>::with_edges(
+            edges
+                .into_iter()
+                .flat_map(|(from, to)| [(from, to, ()), (to, from, ())]),
+            6,
+        );
+
+        b.iter(|| {
+            let is_left = |node| node < id(4);
+            let matching = hopcroft_karp(&graph, is_left);
+            let cover = konig_vertex_cover(&graph, &matching, is_left);
+
+            assert_eq!(matching.len(), 2);
+            assert_eq!(cover.iter_visited().count(), 2);
+            assert!(cover.is_visited(id(4)) && cover.is_visited(id(2)));
+        })
+    }
+
+    #[bench]
+    fn hopcroft_karp_grid_coloring_adj_list(b: &mut Bencher) {
+        let graph = AdjGraph::<(), ()>::with_edges(grid(30, 31), 30 * 31);
+
+        b.iter(|| {
+            let (is_bipartite, coloring) = bfs_bipartite(&graph, id(0));
+            assert!(is_bipartite);
+
+            let is_left = |node| coloring[node] == 1;
+            let matching = hopcroft_karp(&graph, is_left);
+            let cover = konig_vertex_cover(&graph, &matching, is_left);
+
+            assert!(matching.is_perfect());
+            assert_eq!(cover.iter_visited().count(), matching.len());
+
+            for EdgeRef { edge_id, .. } in graph.iter_edges() {
+                assert!(cover.is_visited(edge_id.from()) || cover.is_visited(edge_id.to()));
+            }
+        })
+    }
+
+    #[bench]
+    fn hopcroft_karp_grid_partition_csr_mat(b: &mut Bencher) {
+        // odd number of nodes leaves one node unmatched
+        let graph = CsrGraph::<(), ()>::with_edges(grid(31, 31), 31 * 31);
+        let mut partition = graph.visit_node_map();
+
+        for node in graph.node_ids() {
+            if (*node / 31 + *node % 31) % 2 == 0 {
+                partition.visit(node);
+            }
+        }
+
+        b.iter(|| {
+            let is_left = |node| partition.is_visited(node);
+            let matching = hopcroft_karp(&graph, is_left);
+            let cover = konig_vertex_cover(&graph, &matching, is_left);
+
+            assert_eq!(matching.len(), 31 * 31 / 2);
+            assert_eq!(cover.iter_visited().count(), matching.len());
+        })
+    }
+}
@@ -0,0 +1,271 @@
+use grax_core::collections::NodeIter;
+use grax_core::graph::{EdgeAttribute, EdgeIterAdjacent, NodeAttribute};
+use grax_core::prelude::*;
+
+/// Marks every node whose removal disconnects its component.
+/// Expects an undirected graph, i.e. every edge is stored in both directions.
+pub fn articulation_points<G>(graph: &G) -> G::FixedNodeMap<bool>
+where
+    G: NodeAttribute + EdgeAttribute + EdgeIterAdjacent + NodeIter,
+{
+    hopcroft_tarjan(graph).articulation_points
+}
+
+/// Marks every edge whose removal disconnects its component.
+/// Both directions of a bridge are marked.
+/// Expects an undirected graph, i.e. every edge is stored in both directions.
+pub fn bridges<G>(graph: &G) -> G::FixedEdgeMap<bool>
+where
+    G: NodeAttribute + EdgeAttribute + EdgeIterAdjacent + NodeIter,
+{
+    hopcroft_tarjan(graph).bridges
+}
+
+/// Labels the edges of every biconnected component starting from 1,
+/// where both directions of an edge share the same label.
+/// Expects an undirected graph, i.e. every edge is stored in both directions.
+pub fn biconnected_components<G>(graph: &G) -> (u32, G::FixedEdgeMap<u32>)
+where
+    G: NodeAttribute + EdgeAttribute + EdgeIterAdjacent + NodeIter,
+{
+    let HopcroftTarjan {
+        counter, markers, ..
+    } = hopcroft_tarjan(graph);
+    (counter, markers)
+}
+
+struct HopcroftTarjan<G: NodeAttribute + EdgeAttribute> {
+    articulation_points: G::FixedNodeMap<bool>,
+    bridges: G::FixedEdgeMap<bool>,
+    counter: u32,
+    markers: G::FixedEdgeMap<u32>,
+}
+
+/// Computes articulation points, bridges and biconnected components in a single depth first search.
+fn hopcroft_tarjan<G>(graph: &G) -> HopcroftTarjan<G>
+where
+    G: NodeAttribute + EdgeAttribute + EdgeIterAdjacent + NodeIter,
+{
+    let mut articulation_points = graph.visit_node_map();
+    let mut bridges = graph.visit_edge_map();
+    let mut counter = 0;
+    let mut markers = graph.fixed_edge_map(counter);
+
+    let mut discovered = graph.fixed_node_map(None);
+    let mut low_links = graph.fixed_node_map(0);
+    let mut edge_stack = Vec::new();
+    let mut time = 0;
+
+    for root in graph.node_ids() {
+        if discovered[root].is_some() {
+            continue;
+        }
+
+        discovered[root] = Some(time);
+        low_links[root] = time;
+        time += 1;
+
+        let mut root_children = 0;
+        // (node, tree edge leading to the node, remaining edges, tree edge already skipped)
+        let mut call_stack = vec![(
+            root,
+            None::<EdgeId<G::Key>>,
+            graph.adjacent_edge_ids(root),
+            false,
+        )];
+
+        while let Some((from, parent_edge, edge_ids, skipped)) = call_stack.last_mut() {
+            let from = *from;
+            let parent_edge = *parent_edge;
+
+            if let Some(edge_id) = edge_ids.next() {
+                // only skip the reverse of the tree edge once, so that parallel edges count as back edges
+                if !*skipped && parent_edge.is_some_and(|parent| parent.reverse() == edge_id) {
+                    *skipped = true;
+                    continue;
+                }
+
+                let to = edge_id.to();
+
+                match discovered[to] {
+                    None => {
+                        discovered[to] = Some(time);
+                        low_links[to] = time;
+                        time += 1;
+                        edge_stack.push(edge_id);
+
+                        call_stack.push((to, Some(edge_id), graph.adjacent_edge_ids(to), false));
+                    }
+                    // back edge to an ancestor, edges to descendants were already seen from the other side
+                    Some(index) if discovered[from].is_some_and(|current| index < current) => {
+                        low_links[from] = low_links[from].min(index);
+                        edge_stack.push(edge_id);
+                    }
+                    _ => (),
+                }
+            } else {
+                call_stack.pop();
+
+                if let Some(parent_edge) = parent_edge {
+                    let parent = parent_edge.from();
+                    low_links[parent] = low_links[parent].min(low_links[from]);
+
+                    let parent_index = discovered[parent].unwrap();
+
+                    if low_links[from] >= parent_index {
+                        if parent == root {
+                            root_children += 1;
+                        } else {
+                            articulation_points[parent] = true;
+                        }
+
+                        counter += 1;
+                        while let Some(edge_id) = edge_stack.pop() {
+                            markers[edge_id] = counter;
+                            markers[edge_id.reverse()] = counter;
+
+                            if edge_id == parent_edge {
+                                break;
+                            }
+                        }
+                    }
+
+                    if low_links[from] > parent_index {
+                        bridges[parent_edge] = true;
+                        bridges[parent_edge.reverse()] = true;
+                    }
+                }
+            }
+        }
+
+        articulation_points[root] = root_children >= 2;
+    }
+
+    HopcroftTarjan {
+        articulation_points,
+        bridges,
+        counter,
+        markers,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::{articulation_points, biconnected_components, bridges};
+    use crate::test::{id, weightless_undigraph};
+    use grax_core::collections::{VisitEdgeMap, VisitNodeMap};
+    use grax_core::prelude::*;
+    use grax_impl::*;
+    use test::Bencher;
+
+    fn two_triangles() -> AdjGraph<(), ()> {
+        // 0 - 1 - 2 - 0, 2 - 3, 3 - 4 - 5 - 3
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)];
+        AdjGraph::<(), ()>::with_edges(
+            edges
+                .into_iter()
+                .flat_map(|(from, to)| [(from, to, ()), (to, from, ())]),
+            6,
+        )
+    }
+
+    #[bench]
+    fn articulation_points_two_triangles(b: &mut Bencher) {
+        let graph = two_triangles();
+
+        b.iter(|| {
+            let articulation_points = articulation_points(&graph);
+            assert_eq!(
+                articulation_points.iter_visited().collect::<Vec<_>>(),
+                vec![id(2), id(3)]
+            );
+        })
+    }
+
+    #[bench]
+    fn bridges_two_triangles(b: &mut Bencher) {
+        let graph = two_triangles();
+
+        b.iter(|| {
+            let bridges = bridges(&graph);
+            assert_eq!(bridges.iter_visited().count(), 2);
+            assert!(bridges[EdgeId::new_unchecked(id(2), id(3))]);
+            assert!(bridges[EdgeId::new_unchecked(id(3), id(2))]);
+        })
+    }
+
+    #[bench]
+    fn bridges_parallel_edges(b: &mut Bencher) {
+        let graph =
+            AdjGraph::<(), ()>::with_edges([(0, 1, ()), (1, 0, ()), (0, 1, ()), (1, 0, ())], 2);
+
+        b.iter(|| {
+            let bridges = bridges(&graph);
+            assert_eq!(bridges.iter_visited().count(), 0);
+        })
+    }
+
+    #[bench]
+    fn biconnected_components_two_triangles(b: &mut Bencher) {
+        let graph = two_triangles();
+        let edge = |from, to| EdgeId::new_unchecked(id(from), id(to));
+
+        b.iter(|| {
+            let (counter, markers) = biconnected_components(&graph);
+            assert_eq!(counter, 3);
+            assert_eq!(markers[edge(0, 1)], markers[edge(2, 0)]);
+            assert_eq!(markers[edge(4, 5)], markers[edge(3, 5)]);
+            assert_ne!(markers[edge(2, 3)], markers[edge(1, 2)]);
+            assert_ne!(markers[edge(2, 3)], markers[edge(3, 4)]);
+        })
+    }
+
+    #[bench]
+    fn hopcroft_tarjan_graph1_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = weightless_undigraph("../data/Graph1.txt").unwrap();
+
+        b.iter(|| {
+            let articulation_points = articulation_points(&graph);
+            assert_eq!(articulation_points.iter_visited().count(), 7);
+
+            let bridges = bridges(&graph);
+            assert_eq!(bridges.iter_visited().count(), 2 * 6);
+
+            let (counter, _) = biconnected_components(&graph);
+            assert_eq!(counter, 9);
+        })
+    }
+
+    #[bench]
+    fn hopcroft_tarjan_graph2_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _> = weightless_undigraph("../data/Graph2.txt").unwrap();
+
+        b.iter(|| {
+            let articulation_points = articulation_points(&graph);
+            assert_eq!(articulation_points.iter_visited().count(), 16);
+
+            let bridges = bridges(&graph);
+            assert_eq!(bridges.iter_visited().count(), 2 * 16);
+
+            let (counter, _) = biconnected_components(&graph);
+            assert_eq!(counter, 17);
+        })
+    }
+
+    #[bench]
+    fn hopcroft_tarjan_graph3_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = weightless_undigraph("../data/Graph3.txt").unwrap();
+
+        b.iter(|| {
+            let articulation_points = articulation_points(&graph);
+            assert_eq!(articulation_points.iter_visited().count(), 77);
+
+            let bridges = bridges(&graph);
+            assert_eq!(bridges.iter_visited().count(), 2 * 76);
+
+            let (counter, _) = biconnected_components(&graph);
+            assert_eq!(counter, 81);
+        })
+    }
+}
@@ -0,0 +1,308 @@
+use crate::matching::{Matching, WeightedMatching};
+
+use grax_core::collections::{EdgeIter, NodeIter};
+use grax_core::edge::{weight::*, *};
+use grax_core::graph::NodeAttribute;
+use grax_core::index::{EdgeId, NodeId};
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Kuhn-Munkres assignment of minimum cost in a bipartite graph,
+/// where every node of the smaller side is assigned to a node of the other side.
+/// Missing edges are forbidden pairs and edges between nodes of the same side are ignored.
+/// The partition decides which nodes are on the left side like in `hopcroft_karp`.
+/// Returns none if the smaller side cannot be assigned completely.
+pub fn hungarian<C, G, F>(graph: &G, is_left: F) -> Option<WeightedMatching<C, G>>
+where
+    C: Default
+        + Copy
+        + PartialOrd
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + AddAssign
+        + SubAssign
+        + Debug,
+    G: NodeAttribute + NodeIter + EdgeIter,
+    G::EdgeWeight: Cost<C>,
+    F: Fn(NodeId<G::Key>) -> bool,
+{
+    let (left, right): (Vec<_>, Vec<_>) = graph.node_ids().partition(|&node| is_left(node));
+    let rows_left = left.len() <= right.len();
+    let (rows, columns) = if rows_left {
+        (left, right)
+    } else {
+        (right, left)
+    };
+
+    let mut indices = graph.fixed_node_map(0);
+
+    for (index, &node) in rows.iter().chain(&columns).enumerate() {
+        indices[node] = index;
+    }
+
+    // the dense matrix is one indexed, the row and column zero are sentinels
+    let mut costs = vec![vec![None; columns.len() + 1]; rows.len() + 1];
+
+    for EdgeRef { edge_id, weight } in graph.iter_edges() {
+        let (from, to) = (edge_id.from(), edge_id.to());
+
+        if is_left(from) == is_left(to) {
+            continue;
+        }
+
+        let (row, column) = if is_left(from) == rows_left {
+            (indices[from] + 1, indices[to] - rows.len() + 1)
+        } else {
+            (indices[to] + 1, indices[from] - rows.len() + 1)
+        };
+        let cost = *weight.cost();
+
+        if costs[row][column].is_none_or(|prev| cost < prev) {
+            costs[row][column] = Some(cost);
+        }
+    }
+
+    let assigned = assign(&costs, columns.len())?;
+
+    let mut matching = Matching::new(graph);
+    let mut total_cost = C::default();
+
+    for (column, &row) in assigned.iter().enumerate().skip(1) {
+        if row == 0 {
+            continue;
+        }
+
+        total_cost += costs[row][column].unwrap();
+
+        let (row, column) = (rows[row - 1], columns[column - 1]);
+        let (from, to) = if rows_left {
+            (row, column)
+        } else {
+            (column, row)
+        };
+
+        matching.mates[from] = Some(to);
+        matching.mates[to] = Some(from);
+        matching.edge_ids.push(EdgeId::new_unchecked(from, to));
+    }
+
+    Some(WeightedMatching {
+        matching,
+        cost: total_cost,
+    })
+}
+
+/// Assigns every row to a column by shortest augmenting paths over the reduced costs,
+/// while the row and column potentials keep the reduced costs of all pairs non-negative.
+/// Returns the row assigned to every column or none if a row cannot be assigned.
+fn assign<C>(costs: &[Vec<Option<C>>], column_count: usize) -> Option<Vec<usize>>
+where
+    C: Default
+        + Copy
+        + PartialOrd
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + AddAssign
+        + SubAssign,
+{
+    let zero = C::default();
+
+    let mut row_potentials = vec![zero; costs.len()];
+    let mut column_potentials = vec![zero; column_count + 1];
+    let mut assigned = vec![0; column_count + 1];
+    let mut way = vec![0; column_count + 1];
+
+    for row in 1..costs.len() {
+        assigned[0] = row;
+
+        let mut column = 0;
+        let mut min = vec![None; column_count + 1];
+        let mut used = vec![false; column_count + 1];
+
+        while assigned[column] != 0 {
+            used[column] = true;
+
+            let current = assigned[column];
+            let mut delta = None;
+            let mut next = 0;
+
+            for to in 1..=column_count {
+                if used[to] {
+                    continue;
+                }
+
+                if let Some(cost) = costs[current][to] {
+                    let reduced = cost - row_potentials[current] - column_potentials[to];
+
+                    if min[to].is_none_or(|min| reduced < min) {
+                        min[to] = Some(reduced);
+                        way[to] = column;
+                    }
+                }
+
+                if let Some(min) = min[to]
+                    && delta.is_none_or(|delta| min < delta)
+                {
+                    delta = Some(min);
+                    next = to;
+                }
+            }
+
+            // no column is reachable, so the row remains unassigned
+            let delta = delta?;
+
+            for to in 0..=column_count {
+                if used[to] {
+                    row_potentials[assigned[to]] += delta;
+                    column_potentials[to] -= delta;
+                } else if let Some(min) = &mut min[to] {
+                    *min -= delta;
+                }
+            }
+
+            column = next;
+        }
+
+        while column != 0 {
+            let prev = way[column];
+            assigned[column] = assigned[prev];
+            column = prev;
+        }
+    }
+
+    Some(assigned)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::hungarian;
+    use crate::test::id;
+    use grax_impl::*;
+    use test::Bencher;
+
+    fn bipartite(costs: &[&[Option<f64>]]) -> MatGraph<(), f64> {
+        let rows = costs.len();
+        let columns = costs[0].len();
+
+        let edges = costs.iter().enumerate().flat_map(|(row, costs)| {
+            costs.iter().enumerate().flat_map(move |(column, cost)| {
+                cost.map(|cost| [(row, rows + column, cost), (rows + column, row, cost)])
+                    .into_iter()
+                    .flatten()
+            })
+        });
+
+        MatGraph::<(), f64>::with_edges(edges, rows + columns)
+    }
+
+    fn brute_force(costs: &[Vec<f64>]) -> f64 {
+        fn permute(costs: &[Vec<f64>], row: usize, used: &mut [bool]) -> f64 {
+            if row == costs.len() {
+                return 0.0;
+            }
+
+            let mut min = f64::MAX;
+            for column in 0..used.len() {
+                if !used[column] {
+                    used[column] = true;
+                    min = min.min(costs[row][column] + permute(costs, row + 1, used));
+                    used[column] = false;
+                }
+            }
+            min
+        }
+
+        permute(costs, 0, &mut vec![false; costs[0].len()])
+    }
+
+    #[bench]
+    fn hungarian_square_dense_mat(b: &mut Bencher) {
+        let graph = bipartite(&[
+            &[Some(4.0), Some(1.0), Some(3.0)],
+            &[Some(2.0), Some(0.0), Some(5.0)],
+            &[Some(3.0), Some(2.0), Some(2.0)],
+        ]);
+
+        b.iter(|| {
+            let assignment = hungarian(&graph, |node| node < id(3)).unwrap();
+
+            assert_eq!(assignment.cost, 5.0);
+            assert!(assignment.matching.is_perfect());
+            assert_eq!(assignment.matching.mate(id(0)), Some(id(4)));
+            assert_eq!(assignment.matching.mate(id(1)), Some(id(3)));
+            assert_eq!(assignment.matching.mate(id(2)), Some(id(5)));
+        })
+    }
+
+    #[bench]
+    fn hungarian_rectangular_dense_mat(b: &mut Bencher) {
+        // more workers than tasks, the worker 1 remains unassigned
+        let graph = bipartite(&[
+            &[Some(3.0), Some(7.0)],
+            &[Some(4.0), Some(8.0)],
+            &[Some(9.0), Some(2.0)],
+        ]);
+
+        b.iter(|| {
+            let assignment = hungarian(&graph, |node| node < id(3)).unwrap();
+
+            assert_eq!(assignment.cost, 5.0);
+            assert_eq!(assignment.matching.len(), 2);
+            assert_eq!(assignment.matching.mate(id(1)), None);
+            assert_eq!(assignment.matching.mate(id(3)), Some(id(0)));
+        })
+    }
+
+    #[bench]
+    fn hungarian_forbidden_dense_mat(b: &mut Bencher) {
+        // the cheap pairs are forbidden for the worker 0 except the expensive one
+        let graph = bipartite(&[
+            &[None, None, Some(10.0)],
+            &[Some(1.0), Some(2.0), Some(1.0)],
+            &[Some(2.0), Some(1.0), Some(1.0)],
+        ]);
+
+        b.iter(|| {
+            let assignment = hungarian(&graph, |node| node < id(3)).unwrap();
+
+            assert_eq!(assignment.cost, 12.0);
+            assert_eq!(assignment.matching.mate(id(0)), Some(id(5)));
+        })
+    }
+
+    #[bench]
+    fn hungarian_infeasible_dense_mat(b: &mut Bencher) {
+        // both workers only accept the same task
+        let graph = bipartite(&[&[Some(1.0), None], &[Some(2.0), None]]);
+
+        b.iter(|| {
+            assert!(hungarian(&graph, |node| node < id(2)).is_none());
+        })
+    }
+
+    #[bench]
+    fn hungarian_brute_force_adj_list(b: &mut Bencher) {
+        let costs = (0..7)
+            .map(|row| {
+                (0..7)
+                    .map(|column| ((row * 7 + column) * 37 % 23) as f64)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let edges = costs.iter().enumerate().flat_map(|(row, costs)| {
+            costs
+                .iter()
+                .enumerate()
+                .flat_map(move |(column, &cost)| [(row, 7 + column, cost), (7 + column, row, cost)])
+        });
+        let graph = AdjGraph::<(), f64>::with_edges(edges, 14);
+        let expected = brute_force(&costs);
+
+        b.iter(|| {
+            let assignment = hungarian(&graph, |node| node < id(7)).unwrap();
+            assert_eq!(assignment.cost, expected);
+        })
+    }
+}
@@ -0,0 +1,140 @@
+use super::{_a_star, relax};
+use crate::{cycle::Cycle, distances::AllPairsDistances, distances::Distances, parents::Parents};
+
+use grax_core::collections::{NodeCount, NodeIter};
+use grax_core::edge::weight::*;
+use grax_core::graph::{EdgeAttribute, EdgeIterAdjacent, NodeAttribute};
+use std::fmt::Debug;
+use std::ops::{Add, Sub};
+
+/// All pairs shortest paths for sparse graphs with negative costs.
+/// Bellman-Ford computes potentials, which make every reduced cost non-negative,
+/// so that dijkstra can be run from every node.
+/// Returns a cycle if the graph contains a negative cycle.
+pub fn johnson<C, G>(graph: &G) -> Result<AllPairsDistances<C, G>, Cycle<G>>
+where
+    C: Default + Copy + Add<C, Output = C> + Sub<C, Output = C> + Debug + PartialOrd,
+    G: NodeAttribute + EdgeAttribute + EdgeIterAdjacent + NodeCount + NodeIter,
+    G::EdgeWeight: Cost<C>,
+{
+    // starting at zero everywhere emulates an additional source connected to every node
+    let mut potentials = Distances::new(graph);
+    let mut parents = Parents::new(graph);
+
+    for node in graph.node_ids() {
+        potentials.update(node, C::default());
+    }
+
+    let mut updated = false;
+
+    for _ in 0..graph.node_count() {
+        updated = relax(graph, &mut potentials, &mut parents, &|_| true);
+        if !updated {
+            break;
+        }
+    }
+
+    if updated && relax(graph, &mut potentials, &mut parents, &|_| true) {
+        return Err(Cycle::detect(graph, parents));
+    }
+
+    let potential = |node| *potentials.distance(node).unwrap();
+    let mut all_pairs = AllPairsDistances::new(graph);
+
+    // estimating the negated potential is equivalent to dijkstra on the reduced costs,
+    // but yields the original distances right away
+    for from in graph.node_ids() {
+        let (distances, parents) = _a_star(
+            graph,
+            from,
+            None,
+            |node| C::default() - potential(node),
+            |_| true,
+        );
+
+        for to in graph.node_ids() {
+            all_pairs.distances[from][to] = distances.distance(to).copied();
+            all_pairs.parents[from][to] = parents.parent(to);
+        }
+    }
+
+    Ok(all_pairs)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::johnson;
+    use crate::algorithms::{bellman_ford, floyd_warshall};
+    use crate::test::{digraph, id, undigraph};
+    use grax_core::collections::NodeIter;
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    #[bench]
+    fn johnson_wege_1_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Wege1.txt").unwrap();
+
+        b.iter(|| {
+            let all_pairs = johnson(&graph).unwrap();
+            let total = *all_pairs.distance(id(2), id(0)).unwrap();
+            assert_eq!(total as f32, 6.0);
+        })
+    }
+
+    #[bench]
+    fn johnson_wege_2_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Wege2.txt").unwrap();
+
+        b.iter(|| {
+            let all_pairs = johnson(&graph).unwrap();
+            let expected = floyd_warshall(&graph).unwrap();
+
+            for from in graph.node_ids() {
+                for to in graph.node_ids() {
+                    assert_eq!(
+                        all_pairs.distance(from, to).map(|dist| *dist as f32),
+                        expected.distance(from, to).map(|dist| *dist as f32)
+                    );
+                }
+            }
+
+            let path = all_pairs.path(id(2), id(0)).unwrap();
+            assert_eq!(path.first(), Some(&id(2)));
+            assert_eq!(path.last(), Some(&id(0)));
+        })
+    }
+
+    #[bench]
+    fn johnson_wege_3_di_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/Wege3.txt").unwrap();
+
+        b.iter(|| {
+            let cycle = johnson(&graph).unwrap_err();
+            let cost = cycle
+                .iter_edges()
+                .map(|edge_id| graph[edge_id])
+                .sum::<f64>();
+            assert!(cost < 0.0);
+        })
+    }
+
+    #[bench]
+    fn johnson_g_1_2_undi_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let all_pairs = johnson(&graph).unwrap();
+            let total = *all_pairs.distance(id(0), id(1)).unwrap();
+            assert_eq!(total as f32, 2.36802);
+
+            let tree = bellman_ford(&graph, id(42), |_| true);
+            for to in graph.node_ids() {
+                let dist = all_pairs.distance(id(42), to).unwrap();
+                assert_le!((dist - tree.distances.distance(to).unwrap()).abs(), 1e-9);
+            }
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use super::{MstBuilder, UnionFind};
 use crate::{
-    tree::{Mst, Tree},
+    tree::{Msf, Mst, Tree},
     weight::TotalOrd,
 };
 
@@ -16,18 +16,22 @@ pub struct Kruskal;
 
 impl<C, G> MstBuilder<C, G> for Kruskal
 where
-    C: TotalOrd + Default + AddAssign + Copy + Debug,
+    C: TotalOrd + Default + AddAssign + Copy + Debug + PartialEq,
     G: NodeIter + EdgeIter + EdgeAttribute + NodeAttribute,
     G::EdgeWeight: Cost<C> + Clone + Send + Sync,
 {
     fn mst(self, graph: &G) -> Option<Mst<C, G>> {
         kruskal(graph)
     }
+
+    fn msf(self, graph: &G) -> Msf<C, G> {
+        kruskal_forest(graph)
+    }
 }
 
 pub fn kruskal<C, G>(graph: &G) -> Option<Mst<C, G>>
 where
-    C: TotalOrd + Default + AddAssign + Copy + Debug,
+    C: TotalOrd + Default + AddAssign + Copy + Debug + PartialEq,
     G: NodeIter + EdgeIter + EdgeAttribute + NodeAttribute,
     G::EdgeWeight: Cost<C> + Clone + Send + Sync,
 {
@@ -67,6 +71,44 @@ where
     })
 }
 
+/// Kruskal's algorithm without stopping at the first component,
+/// which keeps track of the cost of every set at its root and of the accepted edges
+pub fn kruskal_forest<C, G>(graph: &G) -> Msf<C, G>
+where
+    C: TotalOrd + Default + AddAssign + Copy + Debug + PartialEq,
+    G: NodeIter + EdgeIter + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C> + Clone + Send + Sync,
+{
+    let mut priority_queue = graph
+        .iter_edges()
+        .map(|edge| edge.to_owned())
+        .collect::<Vec<_>>();
+    priority_queue.par_sort_unstable_by(|a, b| a.weight.cost().total_ord(b.weight.cost()));
+
+    let mut union_find = UnionFind::new(graph);
+    let mut costs = graph.fixed_node_map(C::default());
+    let mut edge_ids = Vec::new();
+
+    for Edge { edge_id, weight } in priority_queue {
+        let root_from = union_find.find(edge_id.from());
+        let root_to = union_find.find(edge_id.to());
+
+        if root_from == root_to {
+            continue;
+        }
+
+        let mut cost = costs[root_from];
+        cost += costs[root_to];
+        cost += *weight.cost();
+
+        let root = union_find.union(root_from, root_to);
+        costs[root] = cost;
+        edge_ids.push(edge_id);
+    }
+
+    union_find.into_forest(graph, &costs, &edge_ids)
+}
+
 #[cfg(test)]
 mod test {
     extern crate test;
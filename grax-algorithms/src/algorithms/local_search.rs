@@ -0,0 +1,556 @@
+use super::TspImprover;
+use crate::{
+    cycle::{Cycle, TspCycle},
+    parents::Parents,
+};
+
+use grax_core::collections::GetEdge;
+use grax_core::edge::weight::*;
+use grax_core::graph::NodeAttribute;
+use grax_core::index::{EdgeId, NodeId};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, SubAssign};
+
+/// Local search over the route, which applies improving 2-opt and or-opt moves
+/// and optionally lin-kernighan moves, until no move improves the route anymore.
+/// The moves are evaluated with symmetric costs like on undirected graphs.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalSearch {
+    /// Maximum number of applied moves
+    pub max_iterations: usize,
+    /// Maximum number of edges exchanged by a lin-kernighan move,
+    /// none only applies 2-opt and or-opt moves
+    pub lin_kernighan: Option<usize>,
+}
+
+impl Default for LocalSearch {
+    fn default() -> Self {
+        Self {
+            max_iterations: 10_000,
+            lin_kernighan: None,
+        }
+    }
+}
+
+impl LocalSearch {
+    pub fn new(max_iterations: usize) -> Self {
+        Self {
+            max_iterations,
+            lin_kernighan: None,
+        }
+    }
+
+    pub fn with_lin_kernighan(mut self, max_depth: usize) -> Self {
+        self.lin_kernighan = Some(max_depth);
+        self
+    }
+}
+
+impl<C, G> TspImprover<C, G> for LocalSearch
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + AddAssign + SubAssign + Debug,
+    G: NodeAttribute + GetEdge,
+    G::EdgeWeight: Cost<C>,
+{
+    fn improve(self, graph: &G, route: TspCycle<C, G>) -> TspCycle<C, G> {
+        // the parents point backwards along the route
+        let mut node_ids = route.cycle.iter().collect::<Vec<_>>();
+        node_ids.reverse();
+
+        if node_ids.len() < 4 {
+            return route;
+        }
+
+        let mut tour = Tour::new(_cost_matrix(graph, &node_ids));
+        let mut iterations = 0;
+
+        while iterations < self.max_iterations {
+            let improved = tour.two_opt()
+                || tour.or_opt()
+                || self
+                    .lin_kernighan
+                    .is_some_and(|max_depth| tour.lin_kernighan(max_depth));
+
+            if !improved {
+                break;
+            }
+            iterations += 1;
+        }
+
+        match tour.total() {
+            Some(cost) if cost < route.cost => tour.into_route(graph, &node_ids, cost),
+            _ => route,
+        }
+    }
+}
+
+/// Dense matrix of the costs between the nodes, where missing edges are none
+pub(crate) fn _cost_matrix<C, G>(graph: &G, node_ids: &[NodeId<G::Key>]) -> Vec<Vec<Option<C>>>
+where
+    C: Copy,
+    G: GetEdge,
+    G::EdgeWeight: Cost<C>,
+{
+    node_ids
+        .iter()
+        .map(|&from| {
+            node_ids
+                .iter()
+                .map(|&to| {
+                    graph
+                        .edge(EdgeId::new_unchecked(from, to))
+                        .map(|edge| *edge.weight.cost())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Route over the indices of a dense cost matrix
+pub(crate) struct Tour<C> {
+    costs: Vec<Vec<Option<C>>>,
+    order: Vec<usize>,
+    positions: Vec<usize>,
+}
+
+impl<C> Tour<C>
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + AddAssign + SubAssign,
+{
+    pub(crate) fn new(costs: Vec<Vec<Option<C>>>) -> Self {
+        let order = (0..costs.len()).collect();
+        Self::with_order(costs, order)
+    }
+
+    pub(crate) fn with_order(costs: Vec<Vec<Option<C>>>, order: Vec<usize>) -> Self {
+        let mut tour = Self {
+            costs,
+            order: Vec::new(),
+            positions: Vec::new(),
+        };
+        tour.reorder(order);
+        tour
+    }
+
+    /// Replaces the order of the nodes along the tour
+    pub(crate) fn reorder(&mut self, order: Vec<usize>) {
+        self.positions = vec![0; order.len()];
+
+        for (position, &node) in order.iter().enumerate() {
+            self.positions[node] = position;
+        }
+
+        self.order = order;
+    }
+
+    /// Visits the cheapest unvisited neighbor next, starting at the first node.
+    /// Returns none if a node has no edge to any unvisited node.
+    pub(crate) fn nearest_neighbor(costs: Vec<Vec<Option<C>>>) -> Option<Self> {
+        let mut visited = vec![false; costs.len()];
+        let mut order = Vec::with_capacity(costs.len());
+        let mut from = 0;
+
+        visited[from] = true;
+        order.push(from);
+
+        while order.len() < costs.len() {
+            from = (0..costs.len())
+                .filter(|&to| !visited[to])
+                .filter_map(|to| costs[from][to].map(|cost| (cost, to)))
+                .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal))?
+                .1;
+
+            visited[from] = true;
+            order.push(from);
+        }
+
+        Some(Self::with_order(costs, order))
+    }
+
+    /// Builds the cycle over the nodes, which are indexed by the tour
+    pub(crate) fn into_route<G>(
+        self,
+        graph: &G,
+        node_ids: &[NodeId<G::Key>],
+        cost: C,
+    ) -> TspCycle<C, G>
+    where
+        G: NodeAttribute,
+    {
+        let mut parents = Parents::new(graph);
+
+        for (&from, &to) in self.order.iter().zip(self.order.iter().cycle().skip(1)) {
+            parents.insert(node_ids[from], node_ids[to]);
+        }
+
+        TspCycle {
+            cost,
+            cycle: Cycle {
+                member: node_ids[self.order[0]],
+                parents,
+            },
+        }
+    }
+
+    pub(crate) fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    fn next(&self, node: usize) -> usize {
+        self.order[(self.positions[node] + 1) % self.len()]
+    }
+
+    fn prev(&self, node: usize) -> usize {
+        self.order[(self.positions[node] + self.len() - 1) % self.len()]
+    }
+
+    pub(crate) fn cost(&self, from: usize, to: usize) -> Option<C> {
+        self.costs[from][to]
+    }
+
+    pub(crate) fn total(&self) -> Option<C> {
+        let mut total = C::default();
+
+        for &from in &self.order {
+            total += self.cost(from, self.next(from))?;
+        }
+
+        Some(total)
+    }
+
+    /// Returns true if the added edges are cheaper than the removed edges
+    fn improves(&self, removed: &[(usize, usize)], added: &[(usize, usize)]) -> bool {
+        let sum = |edges: &[(usize, usize)]| {
+            edges.iter().try_fold(C::default(), |sum, &(from, to)| {
+                self.cost(from, to).map(|cost| sum + cost)
+            })
+        };
+
+        match (sum(removed), sum(added)) {
+            (Some(removed), Some(added)) => added < removed,
+            _ => false,
+        }
+    }
+
+    /// Reverses the path from the first to the last node in the direction of the tour
+    pub(crate) fn reverse(&mut self, first: usize, last: usize) {
+        let len = self.len();
+        let mut start = self.positions[first];
+        let mut end = self.positions[last];
+
+        for _ in 0..((end + len - start) % len).div_ceil(2) {
+            self.order.swap(start, end);
+            self.positions[self.order[start]] = start;
+            self.positions[self.order[end]] = end;
+
+            start = (start + 1) % len;
+            end = (end + len - 1) % len;
+        }
+    }
+
+    /// Replaces two edges by reconnecting their endpoints crosswise
+    fn two_opt(&mut self) -> bool {
+        let len = self.len();
+
+        for first in 0..len - 2 {
+            let (a, b) = (self.order[first], self.order[first + 1]);
+
+            for second in first + 2..len {
+                // both edges would share the node at the beginning of the tour
+                if first == 0 && second == len - 1 {
+                    continue;
+                }
+
+                let (c, d) = (self.order[second], self.order[(second + 1) % len]);
+
+                if self.improves(&[(a, b), (c, d)], &[(a, c), (b, d)]) {
+                    self.reverse(b, c);
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Moves a segment of up to three nodes, possibly reversed, between two other nodes
+    fn or_opt(&mut self) -> bool {
+        let len = self.len();
+
+        for length in 1..=3.min(len - 3) {
+            for start in 0..len {
+                let first = self.order[start];
+                let last = self.order[(start + length - 1) % len];
+                let (prev, next) = (self.prev(first), self.next(last));
+
+                let mut from = next;
+
+                while from != prev {
+                    let to = self.next(from);
+
+                    for (head, tail) in [(first, last), (last, first)] {
+                        if self.improves(
+                            &[(prev, first), (last, next), (from, to)],
+                            &[(prev, next), (from, head), (tail, to)],
+                        ) {
+                            self.move_segment(first, length, from, head == last);
+                            return true;
+                        }
+                    }
+
+                    from = to;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Moves the segment starting at the first node behind the target node
+    fn move_segment(&mut self, first: usize, length: usize, target: usize, reversed: bool) {
+        let start = self.positions[first];
+        let mut segment = (0..length)
+            .map(|offset| self.order[(start + offset) % self.len()])
+            .collect::<Vec<_>>();
+
+        if reversed {
+            segment.reverse();
+        }
+
+        let rest = (length..self.len())
+            .map(|offset| self.order[(start + offset) % self.len()])
+            .collect::<Vec<_>>();
+
+        self.order.clear();
+
+        for node in rest {
+            self.order.push(node);
+
+            if node == target {
+                self.order.extend(&segment);
+            }
+        }
+
+        for (position, &node) in self.order.iter().enumerate() {
+            self.positions[node] = position;
+        }
+    }
+
+    /// Lin-kernighan move as a sequence of 2-opt moves starting at some node t1,
+    /// where the edge (t1, t2) is removed and (t2, t3) added as long as the partial gain stays positive.
+    /// Removing (t4, t3) and closing the tour with (t1, t4) lets t4 continue the sequence,
+    /// which is cut back to the cheapest closed tour found along the way.
+    fn lin_kernighan(&mut self, max_depth: usize) -> bool {
+        let len = self.len();
+        let zero = C::default();
+
+        for t1 in 0..len {
+            let Some(mut best_cost) = self.total() else {
+                return false;
+            };
+            let mut best_depth = 0;
+            let mut steps = Vec::new();
+            let mut added = Vec::new();
+
+            let mut t2 = self.next(t1);
+            let Some(mut gain) = self.cost(t1, t2) else {
+                continue;
+            };
+
+            while steps.len() < max_depth {
+                let mut candidate = None;
+
+                for t3 in 0..len {
+                    if t3 == t1 || t3 == t2 || t3 == self.next(t2) {
+                        continue;
+                    }
+
+                    // added edges of the sequence are never removed again
+                    let t4 = self.prev(t3);
+                    if added.contains(&(t3, t4)) || added.contains(&(t4, t3)) {
+                        continue;
+                    }
+
+                    let (Some(added_cost), Some(removed_cost)) =
+                        (self.cost(t2, t3), self.cost(t4, t3))
+                    else {
+                        continue;
+                    };
+
+                    let mut partial = gain;
+                    partial -= added_cost;
+
+                    if partial > zero {
+                        let next_gain = partial + removed_cost;
+
+                        if candidate.is_none_or(|(best, _, _)| next_gain > best) {
+                            candidate = Some((next_gain, t3, t4));
+                        }
+                    }
+                }
+
+                let Some((next_gain, t3, t4)) = candidate else {
+                    break;
+                };
+
+                self.reverse(t2, t4);
+                steps.push((t2, t4));
+                added.push((t2, t3));
+                gain = next_gain;
+                t2 = t4;
+
+                if let Some(cost) = self.total()
+                    && cost < best_cost
+                {
+                    best_cost = cost;
+                    best_depth = steps.len();
+                }
+            }
+
+            // the reversed paths keep their positions, so reversing them again undoes the steps
+            while steps.len() > best_depth {
+                let (first, last) = steps.pop().unwrap();
+                self.reverse(last, first);
+            }
+
+            if best_depth > 0 {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::LocalSearch;
+    use crate::algorithms::{TspImprover, christofides, nearest_neighbor};
+    use crate::cycle::{Cycle, TspCycle};
+    use crate::parents::Parents;
+    use crate::test::{id, undigraph};
+    use grax_core::collections::NodeCount;
+    use grax_impl::*;
+    use more_asserts::*;
+    use test::Bencher;
+
+    fn assert_route(graph: &AdjGraph<(), f64>, route: &TspCycle<f64, AdjGraph<(), f64>>) {
+        let cost = route
+            .cycle
+            .iter_edges()
+            .map(|edge_id| graph[edge_id])
+            .sum::<f64>();
+
+        assert_eq!(route.cycle.iter().count(), graph.node_count());
+        assert_le!((cost - route.cost).abs(), 1e-9);
+    }
+
+    #[bench]
+    fn local_search_crossing_adj_list(b: &mut Bencher) {
+        // unit square with the diagonals as the crossing edges
+        let diagonal = 2.0_f64.sqrt();
+        let edges = [
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (2, 3, 1.0),
+            (3, 0, 1.0),
+            (0, 2, diagonal),
+            (1, 3, diagonal),
+        ];
+        let graph = AdjGraph::<(), f64>::with_edges(
+            edges
+                .into_iter()
+                .flat_map(|(from, to, cost)| [(from, to, cost), (to, from, cost)]),
+            4,
+        );
+
+        b.iter(|| {
+            let mut parents = Parents::new(&graph);
+            parents.extend([
+                (id(0), id(2)),
+                (id(2), id(1)),
+                (id(1), id(3)),
+                (id(3), id(0)),
+            ]);
+
+            let route = TspCycle {
+                cost: 2.0 + 2.0 * diagonal,
+                cycle: Cycle {
+                    member: id(0),
+                    parents,
+                },
+            };
+            let route = LocalSearch::default().improve(&graph, route);
+
+            assert_eq!(route.cost, 4.0);
+            assert_route(&graph, &route);
+        })
+    }
+
+    #[bench]
+    fn local_search_no_iterations_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+        let initial = nearest_neighbor(&graph).unwrap().cost;
+
+        b.iter(|| {
+            let route = nearest_neighbor(&graph).unwrap();
+            let route = LocalSearch::new(0).improve(&graph, route);
+
+            assert_eq!(route.cost, initial);
+        })
+    }
+
+    #[bench]
+    fn local_search_nearest_neighbor_k_10_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10.txt").unwrap();
+        let initial = nearest_neighbor(&graph).unwrap().cost;
+
+        b.iter(|| {
+            let route = nearest_neighbor(&graph).unwrap();
+            let route = LocalSearch::default().improve(&graph, route);
+
+            assert_route(&graph, &route);
+            assert_le!(route.cost, initial);
+            assert_ge!(route.cost, 38.41);
+        })
+    }
+
+    #[bench]
+    fn local_search_nearest_neighbor_k_12e_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12e.txt").unwrap();
+        let initial = nearest_neighbor(&graph).unwrap().cost;
+
+        b.iter(|| {
+            let route = nearest_neighbor(&graph).unwrap();
+            let route = LocalSearch::default().improve(&graph, route);
+
+            assert_route(&graph, &route);
+            assert_le!(route.cost, initial);
+            assert_ge!(route.cost, 36.13);
+        })
+    }
+
+    #[bench]
+    fn local_search_lin_kernighan_christofides_k_12_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+        let initial = christofides(&graph).unwrap().cost;
+
+        b.iter(|| {
+            let route = christofides(&graph).unwrap();
+            let route = LocalSearch::default()
+                .with_lin_kernighan(5)
+                .improve(&graph, route);
+
+            assert_route(&graph, &route);
+            assert_le!(route.cost, initial);
+            assert_ge!(route.cost, 45.19);
+        })
+    }
+}
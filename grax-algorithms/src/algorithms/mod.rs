@@ -1,43 +1,94 @@
+pub use a_star::*;
 pub use bellman_ford::*;
 pub use bfs::*;
+pub use bidirectional_dijkstra::*;
+pub use blossom::*;
+pub use boruvka::*;
 pub use branch_bound::*;
 pub use brute_force::*;
+pub use capacity_scaling::*;
+pub use christofides::*;
+pub use chu_liu_edmonds::*;
+pub use cost_scaling::*;
 pub use cycle_canceling::*;
 pub use dfs::*;
 pub use dijkstra::*;
+pub use dinic::*;
 pub use double_tree::*;
 pub use edmonds_karp::*;
+pub use floyd_warshall::*;
 pub use ford_fulkerson::*;
+pub use genetic::*;
+pub use gomory_hu::*;
+pub use held_karp::*;
+pub use hopcroft_karp::*;
+pub use hopcroft_tarjan::*;
+pub use hungarian::*;
+pub use johnson::*;
 pub use kahn::*;
 pub use kruskal::*;
+pub use local_search::*;
 pub use nearest_neighbor::*;
+pub use network_simplex::*;
 pub use prim::*;
+pub use push_relabel::*;
+pub use simulated_annealing::*;
 pub use ssp::*;
+pub use stoer_wagner::*;
+pub use tarjan::*;
 pub use union_find::*;
+pub use weighted_blossom::*;
+pub use yen::*;
 
+mod a_star;
 mod bellman_ford;
 mod bfs;
+mod bidirectional_dijkstra;
+mod blossom;
+mod boruvka;
 mod branch_bound;
 mod brute_force;
+mod capacity_scaling;
 mod cdcl;
+mod christofides;
+mod chu_liu_edmonds;
+mod cost_scaling;
 mod cycle_canceling;
 mod dfs;
 mod dijkstra;
+mod dinic;
 mod double_tree;
 mod edmonds_karp;
+mod floyd_warshall;
 mod ford_fulkerson;
+mod genetic;
+mod gomory_hu;
+mod held_karp;
+mod hopcroft_karp;
+mod hopcroft_tarjan;
+mod hungarian;
+mod johnson;
 mod kahn;
 mod kruskal;
+mod local_search;
 mod nearest_neighbor;
+mod network_simplex;
 mod prim;
+mod push_relabel;
+mod residual_network;
+mod simulated_annealing;
 mod ssp;
+mod stoer_wagner;
+mod tarjan;
 mod union_find;
+mod weighted_blossom;
+mod yen;
 
 use crate::{
     cycle::{CycleDetected, TspCycle},
     flow::FlowBundle,
     path::{Path, ShortestPath},
-    tree::{Mst, PathTree, ShortestPathTree},
+    tree::{Msf, Mst, PathTree, ShortestPathTree},
     weight::TotalOrd,
 };
 use grax_core::{
@@ -70,6 +121,9 @@ where
     /// Constructs a minimal spanning tree from a graph
     /// Returns none if such tree cannot be created
     fn mst(self, graph: &G) -> Option<Mst<C, G>>;
+
+    /// Constructs a minimal spanning tree for every component of a graph
+    fn msf(self, graph: &G) -> Msf<C, G>;
 }
 
 pub trait TopologicalSort<G>
@@ -165,6 +219,15 @@ where
     fn solve(graph: &G) -> Option<TspCycle<C, G>>;
 }
 
+pub trait TspImprover<C, G>: Sized + Copy
+where
+    G: NodeAttribute,
+{
+    /// Improves the route until a local optimum or the limit of the implementation is reached
+    /// Returns the improved route with its updated cost
+    fn improve(self, graph: &G, route: TspCycle<C, G>) -> TspCycle<C, G>;
+}
+
 pub fn flow_adaptor<G1, G2, C>(graph: G1) -> G2
 where
     C: Default + Copy + Neg<Output = C>,
@@ -256,3 +319,113 @@ where
 {
     graph.retain_edges(|edge| !edge.weight.is_reverse())
 }
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::{Boruvka, Kruskal, MstBuilder, Prim, bfs_scc};
+    use crate::test::undigraph;
+    use grax_core::collections::{GetEdge, NodeIter};
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    /// Asserts that every tree consists of existing edges, spans its component and sums up to its cost
+    fn assert_forest<B: MstBuilder<f64, AdjGraph<(), f64>>>(builder: B, graph: &AdjGraph<(), f64>) {
+        let (counter, components) = bfs_scc(graph);
+        let msf = builder.msf(graph);
+
+        assert_eq!(msf.trees.len(), counter as usize);
+        assert_eq!(msf.components, components);
+
+        for (component, tree) in &msf.trees {
+            assert_eq!(msf.components[tree.root], *component);
+            assert_eq!(msf.parents.parent(tree.root), None);
+
+            let edge_ids = msf
+                .parents
+                .edge_ids()
+                .filter(|edge_id| msf.components[edge_id.to()] == *component)
+                .collect::<Vec<_>>();
+            let size = graph
+                .node_ids()
+                .filter(|&node_id| msf.components[node_id] == *component)
+                .count();
+            let cost = edge_ids.iter().map(|&edge_id| graph[edge_id]).sum::<f64>();
+
+            assert_eq!(edge_ids.len(), size - 1);
+            assert_le!((cost - tree.cost).abs(), 1e-9);
+
+            for edge_id in edge_ids {
+                assert!(graph.contains_edge_id(edge_id));
+                assert_eq!(msf.components[edge_id.from()], *component);
+            }
+        }
+    }
+
+    fn components() -> AdjGraph<(), f64> {
+        // two components and an isolated node, whose nodes are interleaved
+        AdjGraph::<(), f64>::with_edges(
+            [
+                (0, 2, 1.0),
+                (2, 4, 2.0),
+                (0, 4, 3.0),
+                (1, 5, 1.0),
+                (3, 7, 1.0),
+                (5, 3, 2.0),
+            ]
+            .into_iter()
+            .flat_map(|(from, to, cost)| [(from, to, cost), (to, from, cost)]),
+            8,
+        )
+    }
+
+    fn msf_components<B: MstBuilder<f64, AdjGraph<(), f64>>>(builder: B) {
+        let graph = components();
+        let msf = builder.msf(&graph);
+        let costs = msf.trees.values().map(|tree| tree.cost).collect::<Vec<_>>();
+
+        assert_eq!(costs, [3.0, 4.0, 0.0]);
+        assert_eq!(msf.cost(), 7.0);
+        assert_forest(builder, &graph);
+    }
+
+    fn msf_connected<B: MstBuilder<f64, AdjGraph<(), f64>>>(builder: B) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_20.txt").unwrap();
+        let msf = builder.msf(&graph);
+
+        assert_eq!(msf.trees.len(), 1);
+        assert_eq!(msf.trees[&1].cost as f32, 36.86275);
+        assert_forest(builder, &graph);
+    }
+
+    #[bench]
+    fn msf_components_kruskal_adj_list(b: &mut Bencher) {
+        b.iter(|| msf_components(Kruskal))
+    }
+
+    #[bench]
+    fn msf_components_prim_adj_list(b: &mut Bencher) {
+        b.iter(|| msf_components(Prim))
+    }
+
+    #[bench]
+    fn msf_components_boruvka_adj_list(b: &mut Bencher) {
+        b.iter(|| msf_components(Boruvka))
+    }
+
+    #[bench]
+    fn msf_connected_kruskal_adj_list(b: &mut Bencher) {
+        b.iter(|| msf_connected(Kruskal))
+    }
+
+    #[bench]
+    fn msf_connected_prim_adj_list(b: &mut Bencher) {
+        b.iter(|| msf_connected(Prim))
+    }
+
+    #[bench]
+    fn msf_connected_boruvka_adj_list(b: &mut Bencher) {
+        b.iter(|| msf_connected(Boruvka))
+    }
+}
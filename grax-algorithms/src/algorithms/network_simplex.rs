@@ -0,0 +1,592 @@
+use super::{McfSolver, empty_flow, remove_residual_edges, sum_cost_flow};
+use crate::flow::FlowCostBundle;
+
+use grax_core::{
+    collections::*,
+    edge::{weight::*, *},
+    graph::NodeAttribute,
+    node::{weight::*, *},
+};
+use std::{
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkSimplex;
+
+impl<C, G> McfSolver<C, G> for NetworkSimplex
+where
+    C: Default
+        + Add<C, Output = C>
+        + AddAssign<C>
+        + Sum
+        + Sub<C, Output = C>
+        + SubAssign<C>
+        + Mul<C, Output = C>
+        + Neg<Output = C>
+        + Copy
+        + PartialOrd
+        + Debug,
+    G: EdgeCollection<EdgeWeight = FlowCostBundle<C>>
+        + NodeCollection<NodeWeight = C>
+        + NodeAttribute
+        + NodeIter
+        + EdgeIter
+        + EdgeIterMut
+        + IndexEdgeMut
+        + RemoveEdge,
+{
+    fn solve(graph: &mut G) -> Option<C> {
+        network_simplex(graph)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArcState {
+    Tree,
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, Clone)]
+struct Arc<C> {
+    from: usize,
+    to: usize,
+    capacity: C,
+    cost: C,
+    flow: C,
+    state: ArcState,
+}
+
+/// Primal network simplex on spanning tree bases.
+/// The initial basis connects every node by an artificial arc to an additional root,
+/// whose cost is high enough that no optimal solution keeps flow on them if avoidable.
+/// Entering arcs are chosen by block search: the most violating arc of the first block
+/// containing any violation is selected.
+pub fn network_simplex<C, G>(graph: &mut G) -> Option<C>
+where
+    C: Default
+        + Add<C, Output = C>
+        + AddAssign<C>
+        + Sum
+        + Sub<C, Output = C>
+        + SubAssign<C>
+        + Mul<C, Output = C>
+        + Neg<Output = C>
+        + Copy
+        + PartialOrd
+        + Debug,
+    G: EdgeCollection<EdgeWeight = FlowCostBundle<C>>
+        + NodeCollection<NodeWeight = C>
+        + NodeAttribute
+        + NodeIter
+        + EdgeIter
+        + EdgeIterMut
+        + IndexEdgeMut
+        + RemoveEdge,
+{
+    let zero = C::default();
+    let abs = |value: C| if value < zero { -value } else { value };
+
+    let mut indices = graph.fixed_node_map(0);
+    let mut balances = Vec::new();
+
+    for (index, NodeRef { node_id, weight }) in graph.iter_nodes().enumerate() {
+        indices[node_id] = index;
+        balances.push(*weight.balance());
+    }
+
+    let root = balances.len();
+    let mut edge_ids = Vec::new();
+    let mut arcs = Vec::new();
+    let mut total_cost = zero;
+
+    // residual edges are implied by the arc states
+    for EdgeRef { edge_id, weight } in graph.iter_edges() {
+        if weight.is_reverse() {
+            continue;
+        }
+
+        edge_ids.push(edge_id);
+        arcs.push(Arc {
+            from: indices[edge_id.from()],
+            to: indices[edge_id.to()],
+            capacity: weight.capacity,
+            cost: weight.cost,
+            flow: zero,
+            state: ArcState::Lower,
+        });
+        total_cost += abs(weight.cost);
+    }
+
+    let supply = balances
+        .iter()
+        .filter(|&&balance| balance > zero)
+        .copied()
+        .sum::<C>();
+    let demand = balances
+        .iter()
+        .filter(|&&balance| balance < zero)
+        .map(|&balance| -balance)
+        .sum::<C>();
+
+    if supply != demand {
+        remove_residual_edges(graph);
+        empty_flow(graph);
+        return None;
+    }
+
+    // exceeds the cost of every path over real arcs if there is any supply at all
+    let artificial_cost = total_cost + supply;
+    let real_arcs = arcs.len();
+
+    for (node, &balance) in balances.iter().enumerate() {
+        let (from, to, flow) = if balance >= zero {
+            (node, root, balance)
+        } else {
+            (root, node, -balance)
+        };
+
+        arcs.push(Arc {
+            from,
+            to,
+            capacity: supply,
+            cost: artificial_cost,
+            flow,
+            state: ArcState::Tree,
+        });
+    }
+
+    let mut tree = SpanningTree::new(root + 1);
+    tree.rebuild(&arcs, root);
+
+    let block_size = ((arcs.len() as f64).sqrt().ceil() as usize).max(1);
+    let mut next_arc = 0;
+
+    while let Some(entering) = find_entering(&arcs, &tree, block_size, &mut next_arc) {
+        pivot(&mut arcs, &mut tree, entering);
+    }
+
+    if arcs[real_arcs..].iter().any(|arc| arc.flow > zero) {
+        remove_residual_edges(graph);
+        empty_flow(graph);
+        return None;
+    }
+
+    for (edge_id, arc) in edge_ids.into_iter().zip(arcs) {
+        graph[edge_id].flow = arc.flow;
+    }
+
+    remove_residual_edges(graph);
+
+    Some(sum_cost_flow(graph))
+}
+
+/// Parent pointers and node potentials of the current basis,
+/// such that every tree arc has a reduced cost of zero.
+struct SpanningTree<C> {
+    parents: Vec<usize>,
+    predecessors: Vec<usize>,
+    depths: Vec<usize>,
+    potentials: Vec<C>,
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl<C> SpanningTree<C>
+where
+    C: Default + Copy + Add<C, Output = C> + Sub<C, Output = C>,
+{
+    fn new(node_count: usize) -> Self {
+        Self {
+            parents: vec![usize::MAX; node_count],
+            predecessors: vec![usize::MAX; node_count],
+            depths: vec![0; node_count],
+            potentials: vec![C::default(); node_count],
+            adjacent: vec![Vec::new(); node_count],
+        }
+    }
+
+    fn rebuild(&mut self, arcs: &[Arc<C>], root: usize) {
+        self.adjacent.iter_mut().for_each(Vec::clear);
+
+        for (index, arc) in arcs.iter().enumerate() {
+            if arc.state == ArcState::Tree {
+                self.adjacent[arc.from].push(index);
+                self.adjacent[arc.to].push(index);
+            }
+        }
+
+        self.parents[root] = usize::MAX;
+        self.predecessors[root] = usize::MAX;
+        self.depths[root] = 0;
+        self.potentials[root] = C::default();
+
+        self.update_subtree(arcs, root);
+    }
+
+    /// Replaces the leaving with the entering arc, where `inner` is the endpoint of the entering arc
+    /// in the subtree cut off by the leaving arc. Only the cut off subtree is hung up again.
+    fn exchange(&mut self, arcs: &[Arc<C>], entering: usize, leaving: usize, inner: usize) {
+        for node in [arcs[leaving].from, arcs[leaving].to] {
+            self.adjacent[node].retain(|&index| index != leaving);
+        }
+
+        let arc = &arcs[entering];
+        self.adjacent[arc.from].push(entering);
+        self.adjacent[arc.to].push(entering);
+
+        let (outer, potential) = if arc.from == inner {
+            (arc.to, self.potentials[arc.to] - arc.cost)
+        } else {
+            (arc.from, self.potentials[arc.from] + arc.cost)
+        };
+
+        self.parents[inner] = outer;
+        self.predecessors[inner] = entering;
+        self.depths[inner] = self.depths[outer] + 1;
+        self.potentials[inner] = potential;
+
+        self.update_subtree(arcs, inner);
+    }
+
+    /// Updates parents, depths and potentials below the node from its own
+    fn update_subtree(&mut self, arcs: &[Arc<C>], node: usize) {
+        let mut stack = vec![node];
+
+        while let Some(node) = stack.pop() {
+            for &index in &self.adjacent[node] {
+                if index == self.predecessors[node] {
+                    continue;
+                }
+
+                let arc = &arcs[index];
+                let (child, potential) = if arc.from == node {
+                    (arc.to, self.potentials[node] + arc.cost)
+                } else {
+                    (arc.from, self.potentials[node] - arc.cost)
+                };
+
+                self.parents[child] = node;
+                self.predecessors[child] = index;
+                self.depths[child] = self.depths[node] + 1;
+                self.potentials[child] = potential;
+                stack.push(child);
+            }
+        }
+    }
+
+    fn reduced_cost(&self, arc: &Arc<C>) -> C {
+        arc.cost + self.potentials[arc.from] - self.potentials[arc.to]
+    }
+}
+
+fn find_entering<C>(
+    arcs: &[Arc<C>],
+    tree: &SpanningTree<C>,
+    block_size: usize,
+    next_arc: &mut usize,
+) -> Option<usize>
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + Sub<C, Output = C> + Neg<Output = C>,
+{
+    let zero = C::default();
+    let mut best: Option<(usize, C)> = None;
+
+    for offset in 0..arcs.len() {
+        let index = (*next_arc + offset) % arcs.len();
+        let arc = &arcs[index];
+        let reduced_cost = tree.reduced_cost(arc);
+
+        let violation = match arc.state {
+            ArcState::Lower if reduced_cost < zero => -reduced_cost,
+            ArcState::Upper if reduced_cost > zero => reduced_cost,
+            _ => zero,
+        };
+
+        if violation > zero && best.is_none_or(|(_, max)| violation > max) {
+            best = Some((index, violation));
+        }
+
+        if (offset + 1) % block_size == 0
+            && let Some((index, _)) = best
+        {
+            *next_arc = index + 1;
+            return Some(index);
+        }
+    }
+
+    best.map(|(index, _)| {
+        *next_arc = index + 1;
+        index
+    })
+}
+
+/// Sends as much flow as possible around the cycle closed by the entering arc
+/// and exchanges the entering with the blocking arc.
+fn pivot<C>(arcs: &mut [Arc<C>], tree: &mut SpanningTree<C>, entering: usize)
+where
+    C: Default
+        + Copy
+        + PartialOrd
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + AddAssign<C>
+        + SubAssign<C>,
+{
+    let zero = C::default();
+    let arc = &arcs[entering];
+
+    // flow is sent from first to second over the entering arc
+    let (first, second) = match arc.state {
+        ArcState::Lower => (arc.from, arc.to),
+        _ => (arc.to, arc.from),
+    };
+
+    let mut first_path = Vec::new();
+    let mut second_path = Vec::new();
+    let (mut u, mut v) = (first, second);
+
+    while u != v {
+        if tree.depths[u] >= tree.depths[v] {
+            first_path.push(u);
+            u = tree.parents[u];
+        } else {
+            second_path.push(v);
+            v = tree.parents[v];
+        }
+    }
+
+    // every arc of the cycle with whether flow increases along it,
+    // ordered along the cycle starting at the join so that ties pick the last blocking arc
+    let mut cycle = Vec::with_capacity(first_path.len() + second_path.len() + 1);
+
+    for &node in first_path.iter().rev() {
+        let index = tree.predecessors[node];
+        cycle.push((index, arcs[index].to == node));
+    }
+
+    cycle.push((entering, arcs[entering].state == ArcState::Lower));
+
+    for &node in &second_path {
+        let index = tree.predecessors[node];
+        cycle.push((index, arcs[index].from == node));
+    }
+
+    let residual = |arc: &Arc<C>, forward: bool| {
+        if forward {
+            arc.capacity - arc.flow
+        } else {
+            arc.flow
+        }
+    };
+
+    let (mut leaving, forward) = cycle[0];
+    let mut delta = residual(&arcs[leaving], forward);
+
+    for &(index, forward) in &cycle {
+        let capacity = residual(&arcs[index], forward);
+
+        if capacity <= delta {
+            leaving = index;
+            delta = capacity;
+        }
+    }
+
+    if delta > zero {
+        for &(index, forward) in &cycle {
+            if forward {
+                arcs[index].flow += delta;
+            } else {
+                arcs[index].flow -= delta;
+            }
+        }
+    }
+
+    if leaving == entering {
+        arcs[entering].state = match arcs[entering].state {
+            ArcState::Lower => ArcState::Upper,
+            _ => ArcState::Lower,
+        };
+        return;
+    }
+
+    arcs[leaving].state = if arcs[leaving].flow > zero {
+        ArcState::Upper
+    } else {
+        ArcState::Lower
+    };
+    arcs[entering].state = ArcState::Tree;
+
+    // the leaving arc cuts off the subtree on the side of the entering arc it was found on
+    let inner = if first_path
+        .iter()
+        .any(|&node| tree.predecessors[node] == leaving)
+    {
+        first
+    } else {
+        second
+    };
+
+    tree.exchange(arcs, entering, leaving, inner);
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::NetworkSimplex;
+    use crate::algorithms::{McfSolver, empty_flow};
+    use crate::test::bgraph;
+    use grax_core::collections::{EdgeIter, NodeIter};
+    use grax_impl::*;
+    use test::Bencher;
+
+    #[bench]
+    fn network_simplex_kostenminimal_1_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal1.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 3.0);
+            empty_flow(&mut graph);
+        });
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_2_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal2.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 0.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_3_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal3.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_4_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal4.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_gross_1_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross1.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1537.0);
+
+            // flows respect the capacities and the balances
+            let mut balances = graph
+                .iter_nodes()
+                .map(|node| *node.weight)
+                .collect::<Vec<_>>();
+            for edge in graph.iter_edges() {
+                assert!(edge.weight.flow >= 0.0 && edge.weight.flow <= edge.weight.capacity);
+                balances[*edge.edge_id.from()] -= edge.weight.flow;
+                balances[*edge.edge_id.to()] += edge.weight.flow;
+            }
+            assert!(balances.iter().all(|&balance| balance == 0.0));
+
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_gross_2_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross2.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1838.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_gross_3_adj_list(b: &mut Bencher) {
+        let mut graph: AdjGraph<_, _, true> = bgraph("../data/Kostenminimal_gross3.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_1_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal1.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 3.0);
+            empty_flow(&mut graph);
+        });
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_2_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal2.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 0.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_3_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal3.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_4_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal4.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_gross_1_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal_gross1.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1537.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_gross_2_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal_gross2.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph).unwrap();
+            assert_eq!(cost, 1838.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn network_simplex_kostenminimal_gross_3_csr_mat(b: &mut Bencher) {
+        let mut graph: CsrGraph<_, _, true> = bgraph("../data/Kostenminimal_gross3.txt").unwrap();
+        b.iter(|| {
+            let cost = NetworkSimplex::solve(&mut graph);
+            assert!(cost.is_none());
+        })
+    }
+}
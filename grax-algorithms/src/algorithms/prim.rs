@@ -1,7 +1,7 @@
-use super::MstBuilder;
+use super::{MstBuilder, bfs_scc};
 use crate::{
     parents::Parents,
-    tree::{Mst, Tree},
+    tree::{Msf, MsfTree, Mst, Tree},
     weight::{Bounded, TotalOrd},
 };
 
@@ -9,8 +9,10 @@ use grax_core::collections::VisitNodeMap;
 use grax_core::collections::{NodeCount, NodeIter};
 use grax_core::edge::{weight::*, *};
 use grax_core::graph::{EdgeAttribute, EdgeIterAdjacent, NodeAttribute};
+use grax_core::index::NodeId;
 use orx_priority_queue::DaryHeap;
 use orx_priority_queue::PriorityQueue;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::ops::AddAssign;
 
@@ -26,6 +28,10 @@ where
     fn mst(self, graph: &G) -> Option<Mst<C, G>> {
         prim(graph)
     }
+
+    fn msf(self, graph: &G) -> Msf<C, G> {
+        prim_forest(graph)
+    }
 }
 
 pub fn prim<C, G>(graph: &G) -> Option<Mst<C, G>>
@@ -35,14 +41,113 @@ where
     G::EdgeWeight: Cost<C>,
 {
     let root = graph.node_ids().next()?;
+    Some(prim_where(graph, root, |_| true))
+}
+
+/// Grows a tree from the first node of every component found by `bfs_scc`,
+/// where all trees share the same parents
+pub fn prim_forest<C, G>(graph: &G) -> Msf<C, G>
+where
+    C: Default + Bounded + AddAssign + Copy + Debug + TotalOrd + PartialOrd,
+    G: NodeCount + NodeIter + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+{
+    let (_, components) = bfs_scc(graph);
+    let mut trees = BTreeMap::new();
 
     let mut visited = graph.visit_node_map();
     let mut priority_queue = DaryHeap::<_, _, 4>::with_capacity(graph.node_count() / 2);
-    priority_queue.push(root, C::default());
+    let mut parents = Parents::new(graph);
+    let mut costs = graph.fixed_node_map(C::MAX);
+
+    for root in graph.node_ids() {
+        if trees.contains_key(&components[root]) {
+            continue;
+        }
+
+        let cost = _prim_by(
+            graph,
+            root,
+            |edge| Some(*edge.weight.cost()),
+            &mut visited,
+            &mut priority_queue,
+            &mut parents,
+            &mut costs,
+        );
+        trees.insert(components[root], MsfTree { root, cost });
+    }
+
+    Msf {
+        components,
+        parents,
+        trees,
+    }
+}
+
+/// Minimum spanning tree of the nodes reachable from the root over the edges passing the filter
+pub fn prim_where<C, G, F>(graph: &G, root: NodeId<G::Key>, filter: F) -> Mst<C, G>
+where
+    C: Default + Bounded + AddAssign + Copy + Debug + TotalOrd + PartialOrd,
+    G: NodeCount + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    G::EdgeWeight: Cost<C>,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> bool,
+{
+    prim_by(graph, root, |edge| {
+        let cost = *edge.weight.cost();
+        filter(edge).then_some(cost)
+    })
+}
+
+/// Minimum spanning tree of the nodes reachable from the root, where the edges cost what the closure returns.
+/// Edges without a cost are left out.
+pub fn prim_by<C, G, F>(graph: &G, root: NodeId<G::Key>, cost: F) -> Mst<C, G>
+where
+    C: Default + Bounded + AddAssign + Copy + Debug + TotalOrd + PartialOrd,
+    G: NodeCount + EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> Option<C>,
+{
+    let mut visited = graph.visit_node_map();
+    let mut priority_queue = DaryHeap::<_, _, 4>::with_capacity(graph.node_count() / 2);
 
     // einfach mit W::max init
     let mut parents = Parents::new(graph);
     let mut costs = graph.fixed_node_map(C::MAX);
+
+    let total_cost = _prim_by(
+        graph,
+        root,
+        cost,
+        &mut visited,
+        &mut priority_queue,
+        &mut parents,
+        &mut costs,
+    );
+
+    let tree = Tree { root, parents };
+
+    Mst {
+        tree,
+        cost: total_cost,
+    }
+}
+
+/// Grows the tree of the root into the given maps, which may already hold other trees.
+/// Returns the cost of the tree.
+pub(crate) fn _prim_by<C, G, F>(
+    graph: &G,
+    root: NodeId<G::Key>,
+    cost_of: F,
+    visited: &mut G::FixedNodeMap<bool>,
+    priority_queue: &mut DaryHeap<NodeId<G::Key>, C, 4>,
+    parents: &mut Parents<G>,
+    costs: &mut G::FixedNodeMap<C>,
+) -> C
+where
+    C: Default + Bounded + AddAssign + Copy + Debug + TotalOrd + PartialOrd,
+    G: EdgeIterAdjacent + EdgeAttribute + NodeAttribute,
+    F: Fn(EdgeRef<G::Key, G::EdgeWeight>) -> Option<C>,
+{
+    priority_queue.push(root, C::default());
     let mut total_cost = C::default();
 
     while let Some((from, cost)) = priority_queue.pop() {
@@ -52,26 +157,21 @@ where
         visited.visit(from);
         total_cost += cost;
 
-        for EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(from) {
-            let to = edge_id.to();
-            if !visited.is_visited(to) {
-                let cost = *weight.cost();
+        for edge in graph.iter_adjacent_edges(from) {
+            let to = edge.edge_id.to();
 
-                if costs[to] > cost {
-                    costs[to] = cost;
-                    parents.insert(from, to);
-                    priority_queue.push(to, cost);
-                }
+            if !visited.is_visited(to)
+                && let Some(cost) = cost_of(edge)
+                && costs[to] > cost
+            {
+                costs[to] = cost;
+                parents.insert(from, to);
+                priority_queue.push(to, cost);
             }
         }
     }
 
-    let tree = Tree { root, parents };
-
-    Some(Mst {
-        tree,
-        cost: total_cost,
-    })
+    total_cost
 }
 
 #[cfg(test)]
@@ -0,0 +1,446 @@
+use super::{insert_residual_edges, remove_residual_edges};
+
+use grax_core::collections::{
+    EdgeIter, GetEdge, IndexEdge, IndexEdgeMut, InsertEdge, NodeCount, NodeIter, RemoveEdge,
+};
+use grax_core::edge::weight::*;
+use grax_core::graph::{EdgeIterAdjacent, NodeAttribute};
+use grax_core::prelude::*;
+use std::collections::VecDeque;
+use std::{
+    fmt::Debug,
+    ops::{AddAssign, Sub, SubAssign},
+};
+
+/// Maximum flow using the highest label push-relabel algorithm
+/// with gap and global relabeling heuristics.
+pub fn push_relabel<C, G>(graph: &mut G, source: NodeId<G::Key>, sink: NodeId<G::Key>) -> C
+where
+    C: Default + PartialOrd + Copy + AddAssign + SubAssign + Sub<C, Output = C> + Debug,
+    G: IndexEdge
+        + IndexEdgeMut
+        + NodeAttribute
+        + EdgeIterAdjacent
+        + InsertEdge
+        + RemoveEdge
+        + GetEdge
+        + EdgeIter
+        + NodeIter
+        + NodeCount,
+    G::EdgeWeight: Flow<C> + Capacity<C> + Reverse,
+{
+    insert_residual_edges(graph);
+
+    let total_flow = _push_relabel(graph, source, sink);
+
+    remove_residual_edges(graph);
+
+    total_flow
+}
+
+struct PushRelabel<C, G>
+where
+    C: Debug + Clone + PartialEq,
+    G: NodeAttribute,
+{
+    source: NodeId<G::Key>,
+    sink: NodeId<G::Key>,
+    node_count: usize,
+    adjacent: G::FixedNodeMap<Vec<EdgeId<G::Key>>>,
+    current: G::FixedNodeMap<usize>,
+    excess: G::FixedNodeMap<C>,
+    heights: G::FixedNodeMap<usize>,
+    // number of nodes per height, used to detect gaps
+    counts: Vec<usize>,
+    // active nodes per height, entries become stale once the node is relabeled or discharged
+    buckets: Vec<Vec<NodeId<G::Key>>>,
+    highest: usize,
+}
+
+pub(crate) fn _push_relabel<C, G>(graph: &mut G, source: NodeId<G::Key>, sink: NodeId<G::Key>) -> C
+where
+    C: Default + PartialOrd + Copy + AddAssign + SubAssign + Sub<C, Output = C> + Debug,
+    G: IndexEdge + IndexEdgeMut + NodeAttribute + EdgeIterAdjacent + NodeIter + NodeCount,
+    G::EdgeWeight: Flow<C> + Capacity<C>,
+{
+    // no path leaves the source before reaching the sink
+    if source == sink {
+        return C::default();
+    }
+
+    let node_count = graph.node_count();
+    let mut adjacent = graph.fixed_node_map(Vec::new());
+
+    for node in graph.node_ids() {
+        adjacent[node] = graph.adjacent_edge_ids(node).collect();
+    }
+
+    let mut state = PushRelabel::<C, G> {
+        source,
+        sink,
+        node_count,
+        adjacent,
+        current: graph.fixed_node_map(0),
+        excess: graph.fixed_node_map(C::default()),
+        heights: graph.fixed_node_map(0),
+        counts: vec![0; 2 * node_count + 1],
+        buckets: vec![Vec::new(); 2 * node_count + 1],
+        highest: 0,
+    };
+
+    for index in 0..state.adjacent[source].len() {
+        let edge_id = state.adjacent[source][index];
+        let residual_capacity = graph[edge_id].residual_capacity();
+
+        if residual_capacity > C::default() {
+            state.excess[source] += residual_capacity;
+            state.push(graph, edge_id, residual_capacity);
+        }
+    }
+
+    state.global_relabel(graph);
+
+    let mut relabels = 0;
+
+    while let Some(node) = state.pop_active() {
+        relabels += state.discharge(graph, node);
+
+        // recompute exact heights after every `n` relabels
+        if relabels >= node_count {
+            relabels = 0;
+            state.global_relabel(graph);
+        }
+    }
+
+    state.excess[sink]
+}
+
+impl<C, G> PushRelabel<C, G>
+where
+    C: Default + PartialOrd + Copy + AddAssign + SubAssign + Sub<C, Output = C> + Debug,
+    G: IndexEdge + IndexEdgeMut + NodeAttribute + EdgeIterAdjacent + NodeIter + NodeCount,
+    G::EdgeWeight: Flow<C> + Capacity<C>,
+{
+    fn is_active(&self, node: NodeId<G::Key>) -> bool {
+        node != self.source && node != self.sink && self.excess[node] > C::default()
+    }
+
+    fn activate(&mut self, node: NodeId<G::Key>) {
+        let height = self.heights[node];
+        self.buckets[height].push(node);
+        self.highest = self.highest.max(height);
+    }
+
+    fn pop_active(&mut self) -> Option<NodeId<G::Key>> {
+        loop {
+            match self.buckets[self.highest].pop() {
+                Some(node) if self.heights[node] == self.highest && self.is_active(node) => {
+                    return Some(node);
+                }
+                Some(_) => (),
+                None if self.highest == 0 => return None,
+                None => self.highest -= 1,
+            }
+        }
+    }
+
+    fn push(&mut self, graph: &mut G, edge_id: EdgeId<G::Key>, amount: C) {
+        let to = edge_id.to();
+        let was_active = self.is_active(to);
+
+        *graph[edge_id].flow_mut() += amount;
+        *graph[edge_id.reverse()].flow_mut() -= amount;
+        self.excess[edge_id.from()] -= amount;
+        self.excess[to] += amount;
+
+        if !was_active && self.is_active(to) {
+            self.activate(to);
+        }
+    }
+
+    /// Pushes the excess of the node along admissible edges and relabels the node
+    /// until the excess is gone. Returns the number of relabels.
+    fn discharge(&mut self, graph: &mut G, node: NodeId<G::Key>) -> usize {
+        let mut relabels = 0;
+
+        while self.excess[node] > C::default() {
+            if self.current[node] == self.adjacent[node].len() {
+                relabels += 1;
+
+                if !self.relabel(graph, node) {
+                    break;
+                }
+                continue;
+            }
+
+            let edge_id = self.adjacent[node][self.current[node]];
+            let residual_capacity = graph[edge_id].residual_capacity();
+
+            if residual_capacity > C::default()
+                && self.heights[node] == self.heights[edge_id.to()] + 1
+            {
+                let excess = self.excess[node];
+                let amount = if excess < residual_capacity {
+                    excess
+                } else {
+                    residual_capacity
+                };
+                self.push(graph, edge_id, amount);
+            } else {
+                self.current[node] += 1;
+            }
+        }
+
+        relabels
+    }
+
+    /// Lifts the node just above its lowest residual neighbor.
+    /// Returns false if the node was lifted out of reach by the gap heuristic.
+    fn relabel(&mut self, graph: &G, node: NodeId<G::Key>) -> bool {
+        let old_height = self.heights[node];
+        let new_height = self.adjacent[node]
+            .iter()
+            .filter(|&&edge_id| graph[edge_id].residual_capacity() > C::default())
+            .map(|edge_id| self.heights[edge_id.to()] + 1)
+            .min()
+            .unwrap_or(2 * self.node_count)
+            .min(2 * self.node_count);
+
+        self.counts[old_height] -= 1;
+        self.current[node] = 0;
+
+        // no node is left at the old height, so that nodes above cannot reach the sink anymore
+        if self.counts[old_height] == 0 && old_height < self.node_count {
+            self.gap(graph, old_height);
+            self.heights[node] = new_height.max(self.node_count + 1);
+            self.counts[self.heights[node]] += 1;
+            self.activate(node);
+            return false;
+        }
+
+        self.heights[node] = new_height;
+        self.counts[new_height] += 1;
+        true
+    }
+
+    fn gap(&mut self, graph: &G, height: usize) {
+        for node in graph.node_ids() {
+            let current = self.heights[node];
+
+            if node != self.source && current > height && current < self.node_count {
+                self.counts[current] -= 1;
+                self.heights[node] = self.node_count + 1;
+                self.counts[self.node_count + 1] += 1;
+                self.current[node] = 0;
+
+                if self.is_active(node) {
+                    self.activate(node);
+                }
+            }
+        }
+    }
+
+    /// Sets every height to the residual distance to the sink,
+    /// or to the residual distance to the source offset by the node count if the sink cannot be reached.
+    fn global_relabel(&mut self, graph: &G) {
+        let unreachable = 2 * self.node_count;
+
+        for node in graph.node_ids() {
+            self.heights[node] = unreachable;
+            self.current[node] = 0;
+        }
+
+        self.heights[self.sink] = 0;
+        self.bfs(graph, self.sink);
+        self.heights[self.source] = self.node_count;
+        self.bfs(graph, self.source);
+
+        self.counts.fill(0);
+        self.buckets.iter_mut().for_each(Vec::clear);
+        self.highest = 0;
+
+        for node in graph.node_ids() {
+            self.counts[self.heights[node]] += 1;
+
+            if self.is_active(node) {
+                self.activate(node);
+            }
+        }
+    }
+
+    /// Breadth first search on the reversed residual graph
+    fn bfs(&mut self, graph: &G, root: NodeId<G::Key>) {
+        let unreachable = 2 * self.node_count;
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(node) = queue.pop_front() {
+            let height = self.heights[node] + 1;
+
+            for &edge_id in &self.adjacent[node] {
+                let from = edge_id.to();
+
+                if self.heights[from] == unreachable
+                    && from != self.source
+                    && graph[edge_id.reverse()].residual_capacity() > C::default()
+                {
+                    self.heights[from] = height;
+                    queue.push_back(from);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::push_relabel;
+    use crate::algorithms::{edmonds_karp, empty_flow, flow_adaptor};
+    use crate::test::{digraph, id};
+    use grax_core::collections::EdgeIter;
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    // adj
+
+    #[bench]
+    fn push_relabel_g_1_2_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 0.75447);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn push_relabel_fluss_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 4.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn push_relabel_source_sink_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(0));
+            assert_eq!(total, 0.0);
+            assert!(graph.iter_edges().all(|edge| edge.weight.flow == 0.0));
+        })
+    }
+
+    #[bench]
+    fn push_relabel_fluss2_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Fluss2.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 5.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn push_relabel_g_1_20_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_20.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            empty_flow(&mut graph);
+            let expected = edmonds_karp(&mut graph, id(0), id(7));
+            empty_flow(&mut graph);
+
+            assert_le!((total - expected).abs(), 1e-9);
+        })
+    }
+
+    // dense
+
+    #[bench]
+    fn push_relabel_g_1_2_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let mut graph: MatGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 0.75447);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn push_relabel_fluss_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: MatGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 4.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn push_relabel_fluss2_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/Fluss2.txt").unwrap();
+        let mut graph: MatGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 5.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    // csr
+
+    #[bench]
+    fn push_relabel_g_1_2_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let mut graph: CsrGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 0.75447);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn push_relabel_fluss_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: CsrGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 4.0);
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn push_relabel_fluss2_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/Fluss2.txt").unwrap();
+        let mut graph: CsrGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            assert_eq!(total as f32, 5.0);
+            empty_flow(&mut graph);
+        })
+    }
+}
@@ -0,0 +1,133 @@
+use crate::flow::FlowCostBundle;
+
+use grax_core::{
+    collections::*,
+    edge::{weight::*, *},
+    graph::NodeAttribute,
+    node::{weight::*, *},
+    prelude::*,
+};
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Residual network on contiguous node indices for the min cost flow solvers,
+/// where the arc `2 * i` is the `i`th edge and `2 * i + 1` its reverse.
+/// Residual edges of the graph are implied and therefore skipped.
+pub(crate) struct ResidualNetwork<C, K: Identifier> {
+    pub(crate) edge_ids: Vec<EdgeId<K>>,
+    pub(crate) heads: Vec<usize>,
+    pub(crate) costs: Vec<C>,
+    pub(crate) residuals: Vec<C>,
+    pub(crate) adjacent: Vec<Vec<usize>>,
+    /// Starts with the balances and is zero everywhere for a feasible flow
+    pub(crate) excess: Vec<C>,
+}
+
+impl<C, K> ResidualNetwork<C, K>
+where
+    C: Copy,
+    K: Identifier,
+{
+    pub(crate) fn node_count(&self) -> usize {
+        self.excess.len()
+    }
+
+    pub(crate) fn tail(&self, arc: usize) -> usize {
+        self.heads[arc ^ 1]
+    }
+
+    pub(crate) fn flow(&self, arc: usize) -> C {
+        self.residuals[arc | 1]
+    }
+
+    /// Cost of the arc reduced by the potentials of its nodes
+    pub(crate) fn reduced_cost(&self, potentials: &[C], arc: usize) -> C
+    where
+        C: Add<C, Output = C> + Sub<C, Output = C>,
+    {
+        self.costs[arc] + potentials[self.tail(arc)] - potentials[self.heads[arc]]
+    }
+}
+
+impl<C, K> ResidualNetwork<C, K>
+where
+    C: Default + Copy + PartialOrd + AddAssign + SubAssign + Sub<C, Output = C> + Neg<Output = C>,
+    K: Identifier,
+{
+    pub(crate) fn from_graph<G>(graph: &G) -> Self
+    where
+        G: EdgeCollection<EdgeWeight = FlowCostBundle<C>>
+            + NodeCollection<NodeWeight = C>
+            + NodeAttribute
+            + NodeIter
+            + EdgeIter
+            + Keyed<Key = K>,
+    {
+        let mut indices = graph.fixed_node_map(0);
+        let mut network = Self {
+            edge_ids: Vec::new(),
+            heads: Vec::new(),
+            costs: Vec::new(),
+            residuals: Vec::new(),
+            adjacent: Vec::new(),
+            excess: Vec::new(),
+        };
+
+        for NodeRef { node_id, weight } in graph.iter_nodes() {
+            indices[node_id] = network.add_node(*weight.balance());
+        }
+
+        for EdgeRef { edge_id, weight } in graph.iter_edges() {
+            if weight.is_reverse() {
+                continue;
+            }
+
+            network.edge_ids.push(edge_id);
+            network.add_arc(
+                indices[edge_id.from()],
+                indices[edge_id.to()],
+                weight.capacity,
+                weight.cost,
+            );
+        }
+
+        network
+    }
+
+    pub(crate) fn add_node(&mut self, balance: C) -> usize {
+        self.adjacent.push(Vec::new());
+        self.excess.push(balance);
+        self.excess.len() - 1
+    }
+
+    /// Returns the index of the forward arc
+    pub(crate) fn add_arc(&mut self, from: usize, to: usize, capacity: C, cost: C) -> usize {
+        let arc = self.heads.len();
+
+        self.heads.extend([to, from]);
+        self.costs.extend([cost, -cost]);
+        self.residuals.extend([capacity, C::default()]);
+        self.adjacent[from].push(arc);
+        self.adjacent[to].push(arc + 1);
+
+        arc
+    }
+
+    pub(crate) fn push(&mut self, arc: usize, amount: C) {
+        let (from, to) = (self.tail(arc), self.heads[arc]);
+
+        self.residuals[arc] -= amount;
+        self.residuals[arc ^ 1] += amount;
+        self.excess[from] -= amount;
+        self.excess[to] += amount;
+    }
+
+    /// Writes the flow of every edge back into the graph
+    pub(crate) fn write_flow<G>(&self, graph: &mut G)
+    where
+        G: EdgeCollection<EdgeWeight = FlowCostBundle<C>> + IndexEdgeMut + Keyed<Key = K>,
+    {
+        for (index, &edge_id) in self.edge_ids.iter().enumerate() {
+            graph[edge_id].flow = self.flow(2 * index);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use super::{_cost_matrix, Tour, TspSolver};
+use crate::cycle::TspCycle;
+
+use grax_core::collections::{GetEdge, NodeIter};
+use grax_core::edge::weight::*;
+use grax_core::graph::NodeAttribute;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, SubAssign};
+use std::time::{Duration, Instant};
+
+/// Simulated annealing over random 2-opt moves starting at the nearest neighbor route.
+/// Worse routes are accepted with a probability falling with the temperature,
+/// which cools down geometrically until the last iteration or the end of the time limit, whichever is reached first.
+/// The moves are evaluated with symmetric costs like on undirected graphs.
+#[derive(Debug, Clone, Copy)]
+pub struct SimulatedAnnealing {
+    /// Seed of the random number generator, the same seed results in the same route
+    pub seed: u64,
+    /// Number of proposed moves
+    pub max_iterations: usize,
+    /// Stops before the last iteration once the time is exceeded
+    pub time_limit: Option<Duration>,
+    /// Initial temperature relative to the mean edge cost of the initial route
+    pub temperature: f64,
+}
+
+impl Default for SimulatedAnnealing {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            max_iterations: 200_000,
+            time_limit: None,
+            temperature: 1.0,
+        }
+    }
+}
+
+impl SimulatedAnnealing {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+}
+
+impl<C, G> TspSolver<C, G> for SimulatedAnnealing
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + AddAssign + SubAssign + Into<f64> + Debug,
+    G: NodeAttribute + NodeIter + GetEdge,
+    G::EdgeWeight: Cost<C>,
+{
+    fn solve(graph: &G) -> Option<TspCycle<C, G>> {
+        simulated_annealing(graph, Self::default())
+    }
+}
+
+/// Missing edges are never used by the moves,
+/// but the nearest neighbor route has to exist.
+/// The costs have to be symmetric, as the cost of a reversed part of the route is not reevaluated.
+pub fn simulated_annealing<C, G>(graph: &G, config: SimulatedAnnealing) -> Option<TspCycle<C, G>>
+where
+    C: Default + Copy + PartialOrd + Add<C, Output = C> + AddAssign + SubAssign + Into<f64> + Debug,
+    G: NodeAttribute + NodeIter + GetEdge,
+    G::EdgeWeight: Cost<C>,
+{
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+
+    if node_ids.is_empty() {
+        return None;
+    }
+
+    let mut tour = Tour::nearest_neighbor(_cost_matrix(graph, &node_ids))?;
+    let mut cost = tour.total()?;
+    let len = tour.len();
+
+    if len < 4 {
+        return Some(tour.into_route(graph, &node_ids, cost));
+    }
+
+    let mut best_order = tour.order().to_vec();
+    let mut best_cost = cost;
+
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let initial_temperature = config.temperature * cost.into() / len as f64;
+    let start = Instant::now();
+    let mut elapsed = 0.0;
+
+    for iteration in 0..config.max_iterations {
+        if iteration % 1024 == 0
+            && let Some(limit) = config.time_limit
+        {
+            elapsed = start.elapsed().as_secs_f64() / limit.as_secs_f64();
+
+            if elapsed >= 1.0 {
+                break;
+            }
+        }
+
+        // cools down to a ten thousandth of the initial temperature
+        // along the used fraction of the iterations or the time limit
+        let progress = elapsed.max(iteration as f64 / config.max_iterations as f64);
+        let temperature = initial_temperature * 1e-4_f64.powf(progress);
+
+        let first = rng.gen_range(0..len - 2);
+        let second = rng.gen_range(first + 2..len);
+
+        // both edges would share the node at the beginning of the tour
+        if first == 0 && second == len - 1 {
+            continue;
+        }
+
+        let order = tour.order();
+        let (a, b) = (order[first], order[first + 1]);
+        let (c, d) = (order[second], order[(second + 1) % len]);
+
+        let (Some(ab), Some(cd), Some(ac), Some(bd)) = (
+            tour.cost(a, b),
+            tour.cost(c, d),
+            tour.cost(a, c),
+            tour.cost(b, d),
+        ) else {
+            continue;
+        };
+
+        let removed = ab + cd;
+        let added = ac + bd;
+
+        let accept = added < removed || {
+            let delta = added.into() - removed.into();
+            rng.r#gen::<f64>() < (-delta / temperature).exp()
+        };
+
+        if accept {
+            tour.reverse(b, c);
+            cost += added;
+            cost -= removed;
+
+            if cost < best_cost {
+                best_cost = cost;
+                best_order.clone_from_slice(tour.order());
+            }
+        }
+    }
+
+    // the summed up differences may drift for floating point costs
+    tour.reorder(best_order);
+    let cost = tour.total()?;
+
+    Some(tour.into_route(graph, &node_ids, cost))
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::{SimulatedAnnealing, simulated_annealing};
+    use crate::algorithms::{LocalSearch, TspImprover, nearest_neighbor};
+    use crate::cycle::TspCycle;
+    use crate::test::undigraph;
+    use grax_core::collections::NodeCount;
+    use grax_impl::*;
+    use more_asserts::*;
+    use std::time::Duration;
+    use test::Bencher;
+
+    fn assert_route(graph: &AdjGraph<(), f64>, route: &TspCycle<f64, AdjGraph<(), f64>>) {
+        let cost = route
+            .cycle
+            .iter_edges()
+            .map(|edge_id| graph[edge_id])
+            .sum::<f64>();
+
+        assert_eq!(route.cycle.iter().count(), graph.node_count());
+        assert_le!((cost - route.cost).abs(), 1e-9);
+    }
+
+    #[bench]
+    fn simulated_annealing_k_12_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+
+        b.iter(|| {
+            let route = simulated_annealing(&graph, SimulatedAnnealing::new(42)).unwrap();
+
+            assert_route(&graph, &route);
+            assert_le!(route.cost, 45.19 * 1.1);
+            assert_ge!(route.cost, 45.19 - 1e-9);
+        })
+    }
+
+    fn two_opt(graph: &AdjGraph<(), f64>) -> f64 {
+        let route = nearest_neighbor(graph).unwrap();
+        LocalSearch::default().improve(graph, route).cost
+    }
+
+    #[bench]
+    fn simulated_annealing_k_50_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_50.txt").unwrap();
+        let local_optimum = two_opt(&graph);
+
+        b.iter(|| {
+            let route = simulated_annealing(&graph, SimulatedAnnealing::new(42)).unwrap();
+
+            assert_route(&graph, &route);
+            assert_le!(route.cost, local_optimum);
+        })
+    }
+
+    #[bench]
+    fn simulated_annealing_seed_k_70_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_70.txt").unwrap();
+        let config = SimulatedAnnealing::new(7).with_max_iterations(50_000);
+
+        b.iter(|| {
+            let first = simulated_annealing(&graph, config).unwrap();
+            let second = simulated_annealing(&graph, config).unwrap();
+
+            assert_eq!(first.cost, second.cost);
+            assert_eq!(first.cycle, second.cycle);
+        })
+    }
+
+    #[bench]
+    fn simulated_annealing_time_limit_k_50_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_50.txt").unwrap();
+        let config = SimulatedAnnealing::new(3)
+            .with_max_iterations(usize::MAX)
+            .with_time_limit(Duration::from_millis(100));
+
+        b.iter(|| {
+            // only the time limit ends the run, the cost depends on the speed of the machine
+            let route = simulated_annealing(&graph, config).unwrap();
+            assert_route(&graph, &route);
+        })
+    }
+}
@@ -0,0 +1,233 @@
+use crate::cut::GlobalMinCut;
+
+use grax_core::collections::{EdgeIter, NodeCount, NodeIter};
+use grax_core::edge::{weight::*, *};
+use grax_core::graph::NodeAttribute;
+use orx_priority_queue::{DaryHeap, PriorityQueue};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::ops::{AddAssign, Neg};
+
+/// Stoer-Wagner global minimum cut of an undirected graph.
+/// Every phase orders the nodes by maximum adjacency, where the cut around the last node
+/// is minimal between the last two nodes, which are then merged.
+/// Returns none for graphs with less than two nodes.
+pub fn stoer_wagner<C, G>(graph: &G) -> Option<GlobalMinCut<C, G>>
+where
+    C: Default + Copy + PartialOrd + AddAssign + Neg<Output = C> + Debug,
+    G: NodeAttribute + NodeCount + NodeIter + EdgeIter,
+    G::EdgeWeight: Cost<C>,
+{
+    let node_count = graph.node_count();
+
+    if node_count < 2 {
+        return None;
+    }
+
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+    let mut indices = graph.fixed_node_map(0);
+
+    for (index, &node_id) in node_ids.iter().enumerate() {
+        indices[node_id] = index;
+    }
+
+    // undirected edges are stored in both directions
+    let mut adjacent = vec![HashMap::new(); node_count];
+
+    for EdgeRef { edge_id, weight } in graph.iter_edges() {
+        let from = indices[edge_id.from()];
+        let to = indices[edge_id.to()];
+
+        if from != to {
+            *adjacent[from].entry(to).or_insert_with(C::default) += *weight.cost();
+        }
+    }
+
+    let mut members = (0..node_count).map(|index| vec![index]).collect::<Vec<_>>();
+    let mut active = (0..node_count).collect::<Vec<_>>();
+    let mut best: Option<(C, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        let (last, previous, cut) = maximum_adjacency(&adjacent, &active);
+
+        if best.as_ref().is_none_or(|(min, _)| cut < *min) {
+            best = Some((cut, members[last].clone()));
+        }
+
+        // merges the last node into the previous one
+        let merged = std::mem::take(&mut adjacent[last]);
+
+        for (node, weight) in merged {
+            adjacent[node].remove(&last);
+
+            if node != previous {
+                *adjacent[previous].entry(node).or_insert_with(C::default) += weight;
+                *adjacent[node].entry(previous).or_insert_with(C::default) += weight;
+            }
+        }
+
+        let merged = std::mem::take(&mut members[last]);
+        members[previous].extend(merged);
+        active.retain(|&node| node != last);
+    }
+
+    let (capacity, side) = best?;
+    let mut partition = graph.fixed_node_map(false);
+
+    for index in side {
+        partition[node_ids[index]] = true;
+    }
+
+    Some(GlobalMinCut {
+        capacity,
+        partition,
+    })
+}
+
+/// Returns the last two nodes of the ordering and the cut of the phase
+fn maximum_adjacency<C>(adjacent: &[HashMap<usize, C>], active: &[usize]) -> (usize, usize, C)
+where
+    C: Default + Copy + PartialOrd + AddAssign + Neg<Output = C>,
+{
+    let mut added = vec![false; adjacent.len()];
+    let mut connectivity = vec![C::default(); adjacent.len()];
+    // the min heap is keyed by the negated connectivity
+    let mut priority_queue = DaryHeap::<_, _, 4>::with_capacity(active.len());
+
+    for &node in active {
+        priority_queue.push(node, C::default());
+    }
+
+    let mut last = active[0];
+    let mut previous = active[0];
+    let mut cut = C::default();
+
+    while let Some((node, key)) = priority_queue.pop() {
+        if added[node] || -key < connectivity[node] {
+            continue;
+        }
+        added[node] = true;
+
+        previous = last;
+        last = node;
+        cut = connectivity[node];
+
+        for (&to, &weight) in &adjacent[node] {
+            if !added[to] {
+                connectivity[to] += weight;
+                priority_queue.push(to, -connectivity[to]);
+            }
+        }
+    }
+
+    (last, previous, cut)
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::stoer_wagner;
+    use crate::algorithms::{dinic, empty_flow, flow_adaptor};
+    use crate::test::{id, undigraph};
+    use grax_core::collections::{EdgeIter, NodeIter};
+    use grax_core::edge::EdgeRef;
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    fn stoer_wagner_example() -> AdjGraph<(), f64> {
+        let edges = [
+            (0, 1, 2.0),
+            (0, 4, 3.0),
+            (1, 2, 3.0),
+            (1, 4, 2.0),
+            (1, 5, 2.0),
+            (2, 3, 4.0),
+            (2, 6, 2.0),
+            (3, 6, 2.0),
+            (3, 7, 2.0),
+            (4, 5, 3.0),
+            (5, 6, 1.0),
+            (6, 7, 3.0),
+        ];
+
+        AdjGraph::<(), f64>::with_edges(
+            edges
+                .into_iter()
+                .flat_map(|(from, to, weight)| [(from, to, weight), (to, from, weight)]),
+            8,
+        )
+    }
+
+    #[bench]
+    fn stoer_wagner_example_adj_list(b: &mut Bencher) {
+        let graph = stoer_wagner_example();
+
+        b.iter(|| {
+            let cut = stoer_wagner(&graph).unwrap();
+            assert_eq!(cut.capacity, 4.0);
+
+            for node in [3, 6, 7] {
+                assert_eq!(cut.partition[id(2)], cut.partition[id(node)]);
+            }
+            for node in [0, 1, 4, 5] {
+                assert_ne!(cut.partition[id(2)], cut.partition[id(node)]);
+            }
+        })
+    }
+
+    #[bench]
+    fn stoer_wagner_graph_1_2_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let cut = stoer_wagner(&graph).unwrap();
+
+            let crossing = graph
+                .iter_edges()
+                .filter(|EdgeRef { edge_id, .. }| {
+                    cut.partition[edge_id.from()] && !cut.partition[edge_id.to()]
+                })
+                .map(|EdgeRef { weight, .. }| *weight)
+                .sum::<f64>();
+
+            assert_le!((cut.capacity - crossing).abs(), 1e-9);
+            assert!(graph.node_ids().any(|node_id| cut.partition[node_id]));
+            assert!(graph.node_ids().any(|node_id| !cut.partition[node_id]));
+        })
+    }
+
+    #[bench]
+    fn stoer_wagner_example_dinic(b: &mut Bencher) {
+        let graph = stoer_wagner_example();
+        let mut network: AdjGraph<_, _> = flow_adaptor(graph.clone());
+
+        b.iter(|| {
+            let cut = stoer_wagner(&graph).unwrap();
+
+            // the global minimum cut separates the first node from some other node
+            let min = (1..8)
+                .map(|sink| {
+                    let flow = dinic(&mut network, id(0), id(sink));
+                    empty_flow(&mut network);
+                    flow
+                })
+                .fold(f64::MAX, f64::min);
+
+            assert_eq!(cut.capacity, min);
+        })
+    }
+
+    #[bench]
+    fn stoer_wagner_graph_1_2_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _> = undigraph("../data/G_1_2.txt").unwrap();
+        let adj_list: AdjGraph<_, _> = undigraph("../data/G_1_2.txt").unwrap();
+        let expected = stoer_wagner(&adj_list).unwrap().capacity;
+
+        b.iter(|| {
+            let cut = stoer_wagner(&graph).unwrap();
+            assert_eq!(cut.capacity, expected);
+        })
+    }
+}
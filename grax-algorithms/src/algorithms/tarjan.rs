@@ -0,0 +1,217 @@
+use grax_core::collections::{GetEdge, InsertEdge, NodeIter, VisitNodeMap};
+use grax_core::graph::{Create, EdgeIterAdjacent, NodeAttribute};
+use grax_core::prelude::*;
+
+/// Strongly connected components using Tarjan's algorithm.
+/// Components are labelled starting from 1 in the order they are completed,
+/// which is a reverse topological order of the condensation.
+pub fn tarjan_scc<G>(graph: &G) -> (u32, G::FixedNodeMap<u32>)
+where
+    G: NodeAttribute + EdgeIterAdjacent + NodeIter,
+{
+    let mut counter = 0;
+    let mut markers = graph.fixed_node_map(counter);
+
+    let mut indices = graph.fixed_node_map(None);
+    let mut low_links = graph.fixed_node_map(0);
+    let mut on_stack = graph.visit_node_map();
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+
+    for root in graph.node_ids() {
+        if indices[root].is_some() {
+            continue;
+        }
+
+        indices[root] = Some(next_index);
+        low_links[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack.visit(root);
+
+        // emulates the recursion to not overflow the call stack on large graphs
+        let mut call_stack = vec![(root, graph.adjacent_edge_ids(root))];
+
+        while let Some((from, edge_ids)) = call_stack.last_mut() {
+            let from = *from;
+
+            if let Some(edge_id) = edge_ids.next() {
+                let to = edge_id.to();
+
+                match indices[to] {
+                    None => {
+                        indices[to] = Some(next_index);
+                        low_links[to] = next_index;
+                        next_index += 1;
+                        stack.push(to);
+                        on_stack.visit(to);
+
+                        call_stack.push((to, graph.adjacent_edge_ids(to)));
+                    }
+                    Some(index) if on_stack.is_visited(to) => {
+                        low_links[from] = low_links[from].min(index);
+                    }
+                    _ => (),
+                }
+            } else {
+                call_stack.pop();
+
+                if let Some((parent, _)) = call_stack.last() {
+                    low_links[*parent] = low_links[*parent].min(low_links[from]);
+                }
+
+                // from is the root of a component
+                if Some(low_links[from]) == indices[from] {
+                    counter += 1;
+
+                    while let Some(member) = stack.pop() {
+                        on_stack.unvisit(member);
+                        markers[member] = counter;
+
+                        if member == from {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (counter, markers)
+}
+
+/// Builds the condensation of a graph from its component markers,
+/// as returned by [`tarjan_scc`].
+/// The component `n` is represented by the `n - 1`th node of the returned graph
+/// and two components are connected if any edge connects their members.
+/// For strongly connected components the condensation is acyclic.
+pub fn condensation<G, D>(graph: &G, counter: u32, markers: &G::FixedNodeMap<u32>) -> D
+where
+    G: NodeAttribute + EdgeIterAdjacent + NodeIter,
+    D: Create + NodeIter + InsertEdge + GetEdge,
+    D::NodeWeight: Default,
+    D::EdgeWeight: Default,
+{
+    let node_count = counter as usize;
+    let mut dag = D::with_nodes(
+        (0..node_count).map(|_| D::NodeWeight::default()),
+        node_count,
+    );
+    let components = dag.node_ids().collect::<Vec<_>>();
+
+    for from in graph.node_ids() {
+        for edge_id in graph.adjacent_edge_ids(from) {
+            let from = components[markers[edge_id.from()] as usize - 1];
+            let to = components[markers[edge_id.to()] as usize - 1];
+
+            if from != to && !dag.contains_edge_id(EdgeId::new_unchecked(from, to)) {
+                dag.insert_edge(from, to, D::EdgeWeight::default());
+            }
+        }
+    }
+
+    dag
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+    use super::{condensation, tarjan_scc};
+    use crate::algorithms::dfs_sort;
+    use crate::test::{id, weightless_undigraph};
+    use grax_core::collections::EdgeCount;
+    use grax_impl::*;
+    use test::Bencher;
+
+    #[bench]
+    fn tarjan_scc_directed(b: &mut Bencher) {
+        // 0 <-> 1 --> 2 <-> 3 --> 4
+        let graph = AdjGraph::<(), (), true>::with_edges(
+            [
+                (0, 1, ()),
+                (1, 0, ()),
+                (1, 2, ()),
+                (2, 3, ()),
+                (3, 2, ()),
+                (3, 4, ()),
+            ],
+            5,
+        );
+
+        b.iter(|| {
+            let (counter, markers) = tarjan_scc(&graph);
+            assert_eq!(counter, 3);
+            assert_eq!(markers[id(0)], markers[id(1)]);
+            assert_eq!(markers[id(2)], markers[id(3)]);
+            assert_ne!(markers[id(1)], markers[id(2)]);
+            assert_ne!(markers[id(3)], markers[id(4)]);
+        });
+    }
+
+    #[bench]
+    fn tarjan_scc_condensation(b: &mut Bencher) {
+        // 0 --> 1 --> 2 --> 0, 2 --> 3 --> 4 --> 3, 1 --> 4
+        let graph = AdjGraph::<(), (), true>::with_edges(
+            [
+                (0, 1, ()),
+                (1, 2, ()),
+                (2, 0, ()),
+                (2, 3, ()),
+                (3, 4, ()),
+                (4, 3, ()),
+                (1, 4, ()),
+            ],
+            5,
+        );
+
+        b.iter(|| {
+            let (counter, markers) = tarjan_scc(&graph);
+            assert_eq!(counter, 2);
+
+            let dag: AdjGraph<(), (), true> = condensation(&graph, counter, &markers);
+            assert_eq!(dag.edge_count(), 1);
+            assert!(dfs_sort(&dag).is_ok());
+        });
+    }
+
+    #[bench]
+    fn tarjan_scc_graph1_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = weightless_undigraph("../data/Graph1.txt").unwrap();
+
+        b.iter(|| {
+            let (counter, _) = tarjan_scc(&graph);
+            assert_eq!(counter, 2);
+        });
+    }
+
+    #[bench]
+    fn tarjan_scc_graph2_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = weightless_undigraph("../data/Graph2.txt").unwrap();
+
+        b.iter(|| {
+            let (counter, _) = tarjan_scc(&graph);
+            assert_eq!(counter, 4);
+        });
+    }
+
+    #[bench]
+    fn tarjan_scc_graph3_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _> = weightless_undigraph("../data/Graph3.txt").unwrap();
+
+        b.iter(|| {
+            let (counter, _) = tarjan_scc(&graph);
+            assert_eq!(counter, 4);
+        });
+    }
+
+    #[cfg(feature = "extensive")]
+    #[bench]
+    fn tarjan_scc_graph_gross_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = weightless_undigraph("../data/Graph_gross.txt").unwrap();
+
+        b.iter(|| {
+            let (counter, _) = tarjan_scc(&graph);
+            assert_eq!(counter, 222);
+        });
+    }
+}
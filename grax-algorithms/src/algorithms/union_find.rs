@@ -1,6 +1,15 @@
-use crate::parents::Parents;
-
-use grax_core::{collections::GetNodeMut, graph::NodeAttribute, prelude::NodeId};
+use crate::{
+    parents::Parents,
+    tree::{Msf, MsfTree},
+};
+
+use grax_core::{
+    collections::{GetNodeMut, NodeIter},
+    graph::NodeAttribute,
+    prelude::{EdgeId, NodeId},
+};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
 use std::ops::{Add, AddAssign, Deref, DerefMut};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -101,6 +110,68 @@ impl<G: NodeAttribute> UnionFind<G> {
     }
 }
 
+impl<G: NodeAttribute + NodeIter> UnionFind<G> {
+    /// Splits the sets into one tree each, where the components are labelled like `bfs_scc`
+    /// and the cost of every tree is stored at the root of its set.
+    /// The trees consist of the accepted edges and are hung up at the root of their set.
+    pub(crate) fn into_forest<C: Copy + Debug + PartialEq>(
+        mut self,
+        graph: &G,
+        costs: &G::FixedNodeMap<C>,
+        edge_ids: &[EdgeId<G::Key>],
+    ) -> Msf<C, G> {
+        let mut components = graph.fixed_node_map(0);
+        let mut trees = BTreeMap::new();
+
+        for node_id in graph.node_ids() {
+            let root = self.find(node_id);
+
+            if components[root] == 0 {
+                let component = trees.len() as u32 + 1;
+                components.update_node(root, component);
+                trees.insert(
+                    component,
+                    MsfTree {
+                        root,
+                        cost: costs[root],
+                    },
+                );
+            }
+
+            let component = components[root];
+            components.update_node(node_id, component);
+        }
+
+        let mut adjacent = graph.fixed_node_map(Vec::new());
+
+        for edge_id in edge_ids {
+            adjacent[edge_id.from()].push(edge_id.to());
+            adjacent[edge_id.to()].push(edge_id.from());
+        }
+
+        let mut parents = Parents::new(graph);
+
+        for tree in trees.values() {
+            let mut stack = vec![tree.root];
+
+            while let Some(from) = stack.pop() {
+                for &to in &adjacent[from] {
+                    if parents.parent(from) != Some(to) {
+                        parents.insert(from, to);
+                        stack.push(to);
+                    }
+                }
+            }
+        }
+
+        Msf {
+            components,
+            parents,
+            trees,
+        }
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use crate::test::id;
@@ -0,0 +1,885 @@
+use crate::matching::{Matching, WeightedMatching};
+
+use grax_core::collections::{EdgeIter, NodeIter};
+use grax_core::edge::{weight::*, *};
+use grax_core::graph::NodeAttribute;
+use grax_core::index::EdgeId;
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, Sub, SubAssign};
+
+const NONE: usize = usize::MAX;
+
+/// Minimum weight perfect matching by the primal-dual weighted blossom algorithm.
+/// Edges are weighted by the difference to the largest cost,
+/// so that a maximum weight matching of maximum cardinality minimizes the costs.
+/// Returns none if no perfect matching exists, which is always the case for an odd number of nodes.
+pub fn min_weight_perfect_matching<C, G>(graph: &G) -> Option<WeightedMatching<C, G>>
+where
+    C: Default
+        + Copy
+        + PartialOrd
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + Div<C, Output = C>
+        + AddAssign
+        + SubAssign
+        + From<u8>
+        + Debug,
+    G: NodeAttribute + NodeIter + EdgeIter,
+    G::EdgeWeight: Cost<C>,
+{
+    let node_ids = graph.node_ids().collect::<Vec<_>>();
+    let mut indices = graph.fixed_node_map(0);
+
+    for (index, &node_id) in node_ids.iter().enumerate() {
+        indices[node_id] = index;
+    }
+
+    // undirected edges are stored in both directions, but one suffices
+    let edges = graph
+        .iter_edges()
+        .map(|EdgeRef { edge_id, weight }| {
+            (
+                indices[edge_id.from()],
+                indices[edge_id.to()],
+                *weight.cost(),
+            )
+        })
+        .filter(|(from, to, _)| from < to)
+        .collect::<Vec<_>>();
+
+    let mates = _min_weight_perfect_matching(node_ids.len(), &edges)?;
+
+    let mut matching = Matching::new(graph);
+    let mut cost = C::default();
+
+    for (from, to, weight) in edges {
+        if mates[from] == to && matching.mates[node_ids[from]].is_none() {
+            matching.mates[node_ids[from]] = Some(node_ids[to]);
+            matching.mates[node_ids[to]] = Some(node_ids[from]);
+            matching
+                .edge_ids
+                .push(EdgeId::new_unchecked(node_ids[from], node_ids[to]));
+            cost += weight;
+        }
+    }
+
+    Some(WeightedMatching { matching, cost })
+}
+
+/// Returns the mate of every node of a perfect matching with minimum costs
+/// on contiguous node indices if any.
+pub(crate) fn _min_weight_perfect_matching<C>(
+    node_count: usize,
+    edges: &[(usize, usize, C)],
+) -> Option<Vec<usize>>
+where
+    C: Default
+        + Copy
+        + PartialOrd
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + Div<C, Output = C>
+        + AddAssign
+        + SubAssign
+        + From<u8>,
+{
+    if node_count % 2 == 1 {
+        return None;
+    }
+
+    let max = edges
+        .iter()
+        .map(|&(_, _, cost)| cost)
+        .fold(
+            C::default(),
+            |max, cost| if cost > max { cost } else { max },
+        );
+    let edges = edges
+        .iter()
+        .map(|&(from, to, cost)| (from, to, max - cost))
+        .collect();
+
+    let mut blossom = WeightedBlossom::new(node_count, edges);
+    blossom.solve();
+
+    let mates = blossom.mates();
+    mates.iter().all(|&mate| mate != NONE).then_some(mates)
+}
+
+/// Maximum weight matching of maximum cardinality after Galil's formulation,
+/// where every node and every nontrivial blossom carries a dual variable.
+/// Blossoms are indexed after the nodes and every edge `k` has the endpoints `2k` and `2k + 1`.
+struct WeightedBlossom<C> {
+    node_count: usize,
+    edges: Vec<(usize, usize, C)>,
+    /// Node of every endpoint
+    endpoints: Vec<usize>,
+    /// Remote endpoints of the incident edges of every node
+    neighbors: Vec<Vec<usize>>,
+    /// Remote endpoint of the matched edge of every node
+    mates: Vec<usize>,
+    /// Zero for free, one for even, two for odd and five for visited blossoms
+    labels: Vec<u8>,
+    /// Remote endpoint of the edge through which the blossom got its label
+    label_ends: Vec<usize>,
+    /// Top level blossom of every node
+    in_blossom: Vec<usize>,
+    parents: Vec<usize>,
+    childs: Vec<Vec<usize>>,
+    bases: Vec<usize>,
+    /// Endpoints of the edges connecting the childs of a blossom
+    blossom_endpoints: Vec<Vec<usize>>,
+    /// Edge of least slack to a different even blossom
+    best_edges: Vec<usize>,
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused: Vec<usize>,
+    duals: Vec<C>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+enum Delta {
+    /// No further augmentation is possible
+    Finished,
+    /// Makes the edge to a free node tight
+    Grow(usize),
+    /// Makes the edge between two even blossoms tight
+    Shrink(usize),
+    /// Expands the odd blossom with a zero dual
+    Expand(usize),
+}
+
+impl<C> WeightedBlossom<C>
+where
+    C: Default
+        + Copy
+        + PartialOrd
+        + Add<C, Output = C>
+        + Sub<C, Output = C>
+        + Div<C, Output = C>
+        + AddAssign
+        + SubAssign
+        + From<u8>,
+{
+    fn new(node_count: usize, edges: Vec<(usize, usize, C)>) -> Self {
+        let zero = C::default();
+        let max = edges
+            .iter()
+            .map(|&(_, _, weight)| weight)
+            .fold(zero, |max, weight| if weight > max { weight } else { max });
+
+        let mut endpoints = Vec::with_capacity(edges.len() * 2);
+        let mut neighbors = vec![Vec::new(); node_count];
+
+        for (edge, &(from, to, _)) in edges.iter().enumerate() {
+            endpoints.extend([from, to]);
+            neighbors[from].push(2 * edge + 1);
+            neighbors[to].push(2 * edge);
+        }
+
+        let edge_count = edges.len();
+        let blossom_count = 2 * node_count;
+
+        Self {
+            node_count,
+            edges,
+            endpoints,
+            neighbors,
+            mates: vec![NONE; node_count],
+            labels: vec![0; blossom_count],
+            label_ends: vec![NONE; blossom_count],
+            in_blossom: (0..node_count).collect(),
+            parents: vec![NONE; blossom_count],
+            childs: vec![Vec::new(); blossom_count],
+            bases: (0..node_count)
+                .chain(std::iter::repeat_n(NONE, node_count))
+                .collect(),
+            blossom_endpoints: vec![Vec::new(); blossom_count],
+            best_edges: vec![NONE; blossom_count],
+            blossom_best_edges: vec![None; blossom_count],
+            unused: (node_count..blossom_count).collect(),
+            duals: std::iter::repeat_n(max, node_count)
+                .chain(std::iter::repeat_n(zero, node_count))
+                .collect(),
+            allowed: vec![false; edge_count],
+            queue: Vec::new(),
+        }
+    }
+
+    /// Returns the mate of every node
+    fn mates(&self) -> Vec<usize> {
+        self.mates
+            .iter()
+            .map(|&endpoint| {
+                if endpoint == NONE {
+                    NONE
+                } else {
+                    self.endpoints[endpoint]
+                }
+            })
+            .collect()
+    }
+
+    fn slack(&self, edge: usize) -> C {
+        let (from, to, weight) = self.edges[edge];
+        self.duals[from] + self.duals[to] - weight - weight
+    }
+
+    fn leaves(&self, blossom: usize) -> Vec<usize> {
+        if blossom < self.node_count {
+            return vec![blossom];
+        }
+
+        self.childs[blossom]
+            .iter()
+            .flat_map(|&child| self.leaves(child))
+            .collect()
+    }
+
+    fn assign_label(&mut self, node: usize, label: u8, endpoint: usize) {
+        let blossom = self.in_blossom[node];
+
+        self.labels[node] = label;
+        self.labels[blossom] = label;
+        self.label_ends[node] = endpoint;
+        self.label_ends[blossom] = endpoint;
+        self.best_edges[node] = NONE;
+        self.best_edges[blossom] = NONE;
+
+        if label == 1 {
+            let leaves = self.leaves(blossom);
+            self.queue.extend(leaves);
+        } else {
+            // the mate of the base becomes even
+            let mate = self.mates[self.bases[blossom]];
+            self.assign_label(self.endpoints[mate], 1, mate ^ 1);
+        }
+    }
+
+    /// Traces back from both nodes to find a new blossom or an augmenting path
+    /// Returns the base of the blossom if any
+    fn scan_blossom(&mut self, mut first: usize, mut second: usize) -> Option<usize> {
+        let mut path = Vec::new();
+        let mut base = None;
+
+        while first != NONE || second != NONE {
+            let mut blossom = self.in_blossom[first];
+
+            if self.labels[blossom] & 4 != 0 {
+                base = Some(self.bases[blossom]);
+                break;
+            }
+
+            path.push(blossom);
+            self.labels[blossom] = 5;
+
+            if self.label_ends[blossom] == NONE {
+                // reached the root of the alternating tree
+                first = NONE;
+            } else {
+                first = self.endpoints[self.label_ends[blossom]];
+                blossom = self.in_blossom[first];
+                first = self.endpoints[self.label_ends[blossom]];
+            }
+
+            if second != NONE {
+                std::mem::swap(&mut first, &mut second);
+            }
+        }
+
+        for blossom in path {
+            self.labels[blossom] = 1;
+        }
+
+        base
+    }
+
+    fn add_blossom(&mut self, base: usize, edge: usize) {
+        let (mut first, mut second, _) = self.edges[edge];
+        let base_blossom = self.in_blossom[base];
+        let mut first_blossom = self.in_blossom[first];
+        let mut second_blossom = self.in_blossom[second];
+
+        let blossom = self.unused.pop().unwrap();
+        self.bases[blossom] = base;
+        self.parents[blossom] = NONE;
+        self.parents[base_blossom] = blossom;
+
+        let mut path = Vec::new();
+        let mut endpoints = Vec::new();
+
+        while first_blossom != base_blossom {
+            self.parents[first_blossom] = blossom;
+            path.push(first_blossom);
+            endpoints.push(self.label_ends[first_blossom]);
+            first = self.endpoints[self.label_ends[first_blossom]];
+            first_blossom = self.in_blossom[first];
+        }
+
+        path.push(base_blossom);
+        path.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * edge);
+
+        while second_blossom != base_blossom {
+            self.parents[second_blossom] = blossom;
+            path.push(second_blossom);
+            endpoints.push(self.label_ends[second_blossom] ^ 1);
+            second = self.endpoints[self.label_ends[second_blossom]];
+            second_blossom = self.in_blossom[second];
+        }
+
+        self.labels[blossom] = 1;
+        self.label_ends[blossom] = self.label_ends[base_blossom];
+        self.duals[blossom] = C::default();
+
+        for node in self.leaves_of(&path) {
+            if self.labels[self.in_blossom[node]] == 2 {
+                // odd nodes become even inside the blossom
+                self.queue.push(node);
+            }
+            self.in_blossom[node] = blossom;
+        }
+
+        // the least slack edges to other even blossoms
+        let mut best_to = vec![NONE; 2 * self.node_count];
+
+        for &child in &path {
+            let edges = match self.blossom_best_edges[child].take() {
+                Some(edges) => edges,
+                None => self
+                    .leaves(child)
+                    .into_iter()
+                    .flat_map(|node| self.neighbors[node].iter().map(|endpoint| endpoint / 2))
+                    .collect(),
+            };
+
+            for edge in edges {
+                let (from, to, _) = self.edges[edge];
+                let to = if self.in_blossom[to] == blossom {
+                    from
+                } else {
+                    to
+                };
+                let to_blossom = self.in_blossom[to];
+
+                if to_blossom != blossom
+                    && self.labels[to_blossom] == 1
+                    && (best_to[to_blossom] == NONE
+                        || self.slack(edge) < self.slack(best_to[to_blossom]))
+                {
+                    best_to[to_blossom] = edge;
+                }
+            }
+
+            self.best_edges[child] = NONE;
+        }
+
+        let best_edges = best_to
+            .into_iter()
+            .filter(|&edge| edge != NONE)
+            .collect::<Vec<_>>();
+
+        self.best_edges[blossom] = NONE;
+        for &edge in &best_edges {
+            if self.best_edges[blossom] == NONE
+                || self.slack(edge) < self.slack(self.best_edges[blossom])
+            {
+                self.best_edges[blossom] = edge;
+            }
+        }
+
+        self.blossom_best_edges[blossom] = Some(best_edges);
+        self.childs[blossom] = path;
+        self.blossom_endpoints[blossom] = endpoints;
+    }
+
+    fn leaves_of(&self, blossoms: &[usize]) -> Vec<usize> {
+        blossoms
+            .iter()
+            .flat_map(|&blossom| self.leaves(blossom))
+            .collect()
+    }
+
+    /// Position of the child in the blossom and the direction
+    /// of the even length path from the child to the base
+    fn even_path(&self, blossom: usize, child: usize) -> (usize, isize, isize, usize) {
+        let index = self.childs[blossom]
+            .iter()
+            .position(|&other| other == child)
+            .unwrap();
+        let len = self.childs[blossom].len() as isize;
+
+        if index & 1 == 1 {
+            (index, index as isize - len, 1, 0)
+        } else {
+            (index, index as isize, -1, 1)
+        }
+    }
+
+    fn child_at(&self, blossom: usize, position: isize) -> usize {
+        let len = self.childs[blossom].len() as isize;
+        self.childs[blossom][position.rem_euclid(len) as usize]
+    }
+
+    fn endpoint_at(&self, blossom: usize, position: isize) -> usize {
+        let len = self.blossom_endpoints[blossom].len() as isize;
+        self.blossom_endpoints[blossom][position.rem_euclid(len) as usize]
+    }
+
+    fn expand_blossom(&mut self, blossom: usize, end_stage: bool) {
+        for child in self.childs[blossom].clone() {
+            self.parents[child] = NONE;
+
+            if child < self.node_count {
+                self.in_blossom[child] = child;
+            } else if end_stage && self.duals[child] == C::default() {
+                self.expand_blossom(child, end_stage);
+            } else {
+                for node in self.leaves(child) {
+                    self.in_blossom[node] = child;
+                }
+            }
+        }
+
+        if !end_stage && self.labels[blossom] == 2 {
+            // relabels the childs on the even path from the entry child to the base
+            let entry = self.in_blossom[self.endpoints[self.label_ends[blossom] ^ 1]];
+            let (_, mut position, step, trick) = self.even_path(blossom, entry);
+            let trick_position = trick as isize;
+            let mut endpoint = self.label_ends[blossom];
+
+            while position != 0 {
+                let current = self.endpoint_at(blossom, position - trick_position);
+
+                self.labels[self.endpoints[endpoint ^ 1]] = 0;
+                self.labels[self.endpoints[current ^ trick ^ 1]] = 0;
+                self.assign_label(self.endpoints[endpoint ^ 1], 2, endpoint);
+                self.allowed[current / 2] = true;
+
+                position += step;
+                endpoint = self.endpoint_at(blossom, position - trick_position) ^ trick;
+                self.allowed[endpoint / 2] = true;
+                position += step;
+            }
+
+            let child = self.child_at(blossom, position);
+            let node = self.endpoints[endpoint ^ 1];
+            self.labels[node] = 2;
+            self.labels[child] = 2;
+            self.label_ends[node] = endpoint;
+            self.label_ends[child] = endpoint;
+            self.best_edges[child] = NONE;
+            position += step;
+
+            // childs on the odd path are free unless reachable from outside
+            while self.child_at(blossom, position) != entry {
+                let child = self.child_at(blossom, position);
+                position += step;
+
+                if self.labels[child] == 1 {
+                    continue;
+                }
+
+                if let Some(node) = self
+                    .leaves(child)
+                    .into_iter()
+                    .find(|&node| self.labels[node] != 0)
+                {
+                    self.labels[node] = 0;
+                    self.labels[self.endpoints[self.mates[self.bases[child]]]] = 0;
+                    self.assign_label(node, 2, self.label_ends[node]);
+                }
+            }
+        }
+
+        self.labels[blossom] = 0;
+        self.label_ends[blossom] = NONE;
+        self.childs[blossom].clear();
+        self.blossom_endpoints[blossom].clear();
+        self.bases[blossom] = NONE;
+        self.blossom_best_edges[blossom] = None;
+        self.best_edges[blossom] = NONE;
+        self.unused.push(blossom);
+    }
+
+    /// Swaps the matched and unmatched edges on the path from the node to the base
+    /// and rotates the blossom, so that the node becomes the new base
+    fn augment_blossom(&mut self, blossom: usize, node: usize) {
+        let mut child = node;
+        while self.parents[child] != blossom {
+            child = self.parents[child];
+        }
+
+        if child >= self.node_count {
+            self.augment_blossom(child, node);
+        }
+
+        let (index, mut position, step, trick) = self.even_path(blossom, child);
+        let trick_position = trick as isize;
+
+        while position != 0 {
+            position += step;
+            let child = self.child_at(blossom, position);
+            let endpoint = self.endpoint_at(blossom, position - trick_position) ^ trick;
+
+            if child >= self.node_count {
+                self.augment_blossom(child, self.endpoints[endpoint]);
+            }
+
+            position += step;
+            let child = self.child_at(blossom, position);
+
+            if child >= self.node_count {
+                self.augment_blossom(child, self.endpoints[endpoint ^ 1]);
+            }
+
+            self.mates[self.endpoints[endpoint]] = endpoint ^ 1;
+            self.mates[self.endpoints[endpoint ^ 1]] = endpoint;
+        }
+
+        self.childs[blossom].rotate_left(index);
+        self.blossom_endpoints[blossom].rotate_left(index);
+        self.bases[blossom] = self.bases[self.childs[blossom][0]];
+    }
+
+    fn augment_matching(&mut self, edge: usize) {
+        let (from, to, _) = self.edges[edge];
+
+        for (mut node, mut endpoint) in [(from, 2 * edge + 1), (to, 2 * edge)] {
+            loop {
+                let blossom = self.in_blossom[node];
+
+                if blossom >= self.node_count {
+                    self.augment_blossom(blossom, node);
+                }
+
+                self.mates[node] = endpoint;
+
+                if self.label_ends[blossom] == NONE {
+                    break;
+                }
+
+                let odd = self.in_blossom[self.endpoints[self.label_ends[blossom]]];
+                node = self.endpoints[self.label_ends[odd]];
+                let inner = self.endpoints[self.label_ends[odd] ^ 1];
+
+                if odd >= self.node_count {
+                    self.augment_blossom(odd, inner);
+                }
+
+                self.mates[inner] = self.label_ends[odd];
+                endpoint = self.label_ends[odd] ^ 1;
+            }
+        }
+    }
+
+    fn solve(&mut self) {
+        let zero = C::default();
+
+        for _ in 0..self.node_count {
+            self.labels.fill(0);
+            self.best_edges.fill(NONE);
+            for best_edges in &mut self.blossom_best_edges[self.node_count..] {
+                *best_edges = None;
+            }
+            self.allowed.fill(false);
+            self.queue.clear();
+
+            for node in 0..self.node_count {
+                if self.mates[node] == NONE && self.labels[self.in_blossom[node]] == 0 {
+                    self.assign_label(node, 1, NONE);
+                }
+            }
+
+            let mut augmented = false;
+
+            loop {
+                while !augmented && let Some(node) = self.queue.pop() {
+                    for index in 0..self.neighbors[node].len() {
+                        let endpoint = self.neighbors[node][index];
+                        let edge = endpoint / 2;
+                        let other = self.endpoints[endpoint];
+
+                        if self.in_blossom[node] == self.in_blossom[other] {
+                            continue;
+                        }
+
+                        let slack = self.slack(edge);
+                        if !self.allowed[edge] && slack <= zero {
+                            self.allowed[edge] = true;
+                        }
+
+                        let other_label = self.labels[self.in_blossom[other]];
+
+                        if self.allowed[edge] {
+                            if other_label == 0 {
+                                self.assign_label(other, 2, endpoint ^ 1);
+                            } else if other_label == 1 {
+                                match self.scan_blossom(node, other) {
+                                    Some(base) => self.add_blossom(base, edge),
+                                    None => {
+                                        self.augment_matching(edge);
+                                        augmented = true;
+                                        break;
+                                    }
+                                }
+                            } else if self.labels[other] == 0 {
+                                self.labels[other] = 2;
+                                self.label_ends[other] = endpoint ^ 1;
+                            }
+                        } else if other_label == 1 {
+                            let blossom = self.in_blossom[node];
+                            if self.best_edges[blossom] == NONE
+                                || slack < self.slack(self.best_edges[blossom])
+                            {
+                                self.best_edges[blossom] = edge;
+                            }
+                        } else if self.labels[other] == 0
+                            && (self.best_edges[other] == NONE
+                                || slack < self.slack(self.best_edges[other]))
+                        {
+                            self.best_edges[other] = edge;
+                        }
+                    }
+                }
+
+                if augmented {
+                    break;
+                }
+
+                let (delta, kind) = self.delta();
+
+                for node in 0..self.node_count {
+                    match self.labels[self.in_blossom[node]] {
+                        1 => self.duals[node] -= delta,
+                        2 => self.duals[node] += delta,
+                        _ => (),
+                    }
+                }
+
+                for blossom in self.node_count..2 * self.node_count {
+                    if self.bases[blossom] != NONE && self.parents[blossom] == NONE {
+                        match self.labels[blossom] {
+                            1 => self.duals[blossom] += delta,
+                            2 => self.duals[blossom] -= delta,
+                            _ => (),
+                        }
+                    }
+                }
+
+                match kind {
+                    Delta::Finished => break,
+                    Delta::Grow(edge) => {
+                        self.allowed[edge] = true;
+                        let (mut from, to, _) = self.edges[edge];
+                        if self.labels[self.in_blossom[from]] == 0 {
+                            from = to;
+                        }
+                        self.queue.push(from);
+                    }
+                    Delta::Shrink(edge) => {
+                        self.allowed[edge] = true;
+                        let (from, _, _) = self.edges[edge];
+                        self.queue.push(from);
+                    }
+                    Delta::Expand(blossom) => self.expand_blossom(blossom, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+
+            // blossoms with a zero dual are no longer needed
+            for blossom in self.node_count..2 * self.node_count {
+                if self.parents[blossom] == NONE
+                    && self.bases[blossom] != NONE
+                    && self.labels[blossom] == 1
+                    && self.duals[blossom] == zero
+                {
+                    self.expand_blossom(blossom, true);
+                }
+            }
+        }
+    }
+
+    /// Smallest change of the duals that allows further progress
+    fn delta(&self) -> (C, Delta) {
+        let zero = C::default();
+        let two = C::from(2);
+        let mut best: Option<(C, Delta)> = None;
+
+        let mut consider = |delta: C, kind: Delta| {
+            if best.as_ref().is_none_or(|(min, _)| delta < *min) {
+                best = Some((delta, kind));
+            }
+        };
+
+        for node in 0..self.node_count {
+            if self.labels[self.in_blossom[node]] == 0 && self.best_edges[node] != NONE {
+                consider(
+                    self.slack(self.best_edges[node]),
+                    Delta::Grow(self.best_edges[node]),
+                );
+            }
+        }
+
+        for blossom in 0..2 * self.node_count {
+            if self.parents[blossom] == NONE
+                && self.labels[blossom] == 1
+                && self.best_edges[blossom] != NONE
+            {
+                let slack = self.slack(self.best_edges[blossom]);
+                consider(slack / two, Delta::Shrink(self.best_edges[blossom]));
+            }
+        }
+
+        for blossom in self.node_count..2 * self.node_count {
+            if self.bases[blossom] != NONE
+                && self.parents[blossom] == NONE
+                && self.labels[blossom] == 2
+            {
+                consider(self.duals[blossom], Delta::Expand(blossom));
+            }
+        }
+
+        best.unwrap_or_else(|| {
+            // the matching is of maximum cardinality, the remaining duals are lowered
+            let min = self.duals[..self.node_count]
+                .iter()
+                .copied()
+                .fold(None, |min: Option<C>, dual| match min {
+                    Some(min) if min <= dual => Some(min),
+                    _ => Some(dual),
+                })
+                .unwrap_or(zero);
+
+            (if min > zero { min } else { zero }, Delta::Finished)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::min_weight_perfect_matching;
+    use crate::test::{id, undigraph};
+    use grax_core::collections::EdgeIter;
+    use grax_core::edge::EdgeRef;
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    /// Minimum perfect matching by dynamic programming over the subsets of unmatched nodes
+    fn brute_force(costs: &[Vec<f64>]) -> f64 {
+        let node_count = costs.len();
+        let mut min = vec![f64::MAX; 1 << node_count];
+        min[0] = 0.0;
+
+        for set in 1..1usize << node_count {
+            let first = set.trailing_zeros() as usize;
+
+            for (second, cost) in costs[first].iter().enumerate().skip(first + 1) {
+                if set & (1 << second) != 0 {
+                    let rest = set & !(1 << first) & !(1 << second);
+                    min[set] = min[set].min(min[rest] + cost);
+                }
+            }
+        }
+
+        min[(1 << node_count) - 1]
+    }
+
+    fn costs<G>(graph: &G, node_count: usize) -> Vec<Vec<f64>>
+    where
+        G: EdgeIter<EdgeWeight = f64, Key = usize>,
+    {
+        let mut costs = vec![vec![f64::MAX; node_count]; node_count];
+        for EdgeRef { edge_id, weight } in graph.iter_edges() {
+            costs[*edge_id.from()][*edge_id.to()] = *weight;
+        }
+        costs
+    }
+
+    #[bench]
+    fn min_weight_perfect_matching_square_adj_list(b: &mut Bencher) {
+        // the diagonals are expensive, the sides of the square are cheap in pairs
+        let edges = [
+            (0, 1, 1.0),
+            (1, 2, 4.0),
+            (2, 3, 1.0),
+            (3, 0, 4.0),
+            (0, 2, 9.0),
+            (1, 3, 9.0),
+        ];
+        let graph = AdjGraph::<(), f64>::with_edges(
+            edges
+                .into_iter()
+                .flat_map(|(from, to, cost)| [(from, to, cost), (to, from, cost)]),
+            4,
+        );
+
+        b.iter(|| {
+            let matching = min_weight_perfect_matching(&graph).unwrap();
+
+            assert_eq!(matching.cost, 2.0);
+            assert_eq!(matching.matching.mate(id(0)), Some(id(1)));
+            assert_eq!(matching.matching.mate(id(2)), Some(id(3)));
+        })
+    }
+
+    #[bench]
+    fn min_weight_perfect_matching_odd_adj_list(b: &mut Bencher) {
+        let graph = AdjGraph::<(), f64>::with_edges([(0, 1, 1.0), (1, 0, 1.0)], 3);
+
+        b.iter(|| {
+            assert!(min_weight_perfect_matching(&graph).is_none());
+        })
+    }
+
+    #[bench]
+    fn min_weight_perfect_matching_k_10_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _> = undigraph("../data/K_10.txt").unwrap();
+        let expected = brute_force(&costs(&graph, 10));
+
+        b.iter(|| {
+            let matching = min_weight_perfect_matching(&graph).unwrap();
+
+            assert!(matching.matching.is_perfect());
+            assert_le!((matching.cost - expected).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn min_weight_perfect_matching_k_12_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _> = undigraph("../data/K_12.txt").unwrap();
+        let expected = brute_force(&costs(&graph, 12));
+
+        b.iter(|| {
+            let matching = min_weight_perfect_matching(&graph).unwrap();
+            let cost = matching
+                .matching
+                .edge_ids
+                .iter()
+                .map(|&edge_id| graph[edge_id])
+                .sum::<f64>();
+
+            assert_le!((matching.cost - expected).abs(), 1e-9);
+            assert_le!((cost - expected).abs(), 1e-9);
+        })
+    }
+
+    #[bench]
+    fn min_weight_perfect_matching_k_12e_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _> = undigraph("../data/K_12e.txt").unwrap();
+        let expected = brute_force(&costs(&graph, 12));
+
+        b.iter(|| {
+            let matching = min_weight_perfect_matching(&graph).unwrap();
+            assert_le!((matching.cost - expected).abs(), 1e-9);
+        })
+    }
+}
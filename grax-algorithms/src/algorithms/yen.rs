@@ -0,0 +1,204 @@
+use super::ShortestPathFinder;
+use crate::{parents::Parents, path::WeightedPath};
+
+use grax_core::collections::{EdgeCollection, IndexEdge};
+use grax_core::edge::weight::*;
+use grax_core::graph::NodeAttribute;
+use grax_core::prelude::*;
+use std::fmt::Debug;
+use std::ops::Add;
+
+/// Yen's algorithm for the `k` shortest loopless paths between two nodes,
+/// ranked by their cost in ascending order.
+/// Every candidate deviates from a previous path at a spur node,
+/// where the finder searches the spur path with the edges of the known paths
+/// sharing the same root and the nodes of the root filtered out.
+pub fn yen<C, G, S>(
+    graph: &G,
+    from: NodeId<G::Key>,
+    to: NodeId<G::Key>,
+    k: usize,
+    finder: S,
+) -> Vec<WeightedPath<C, G>>
+where
+    C: Default + Copy + Add<C, Output = C> + Debug + PartialOrd,
+    G: NodeAttribute + EdgeCollection + IndexEdge,
+    G::EdgeWeight: Cost<C>,
+    S: ShortestPathFinder<C, G>,
+{
+    let mut paths = Vec::with_capacity(k);
+
+    if k == 0 {
+        return paths;
+    }
+
+    match finder.shortest_path(graph, from, to) {
+        Some(path) => paths.push(WeightedPath {
+            cost: path.distance,
+            nodes: path_nodes(&path.parents, from, to),
+        }),
+        None => return paths,
+    }
+
+    let mut candidates: Vec<WeightedPath<C, G>> = Vec::new();
+
+    while paths.len() < k {
+        let previous = paths.last().unwrap().nodes.clone();
+        let mut root_cost = C::default();
+        let mut removed_nodes = graph.visit_node_map();
+
+        for (index, &spur) in previous[..previous.len() - 1].iter().enumerate() {
+            let root = &previous[..=index];
+            let removed_edges = paths
+                .iter()
+                .filter(|path| path.nodes.len() > index + 1 && path.nodes[..=index] == *root)
+                .map(|path| EdgeId::new_unchecked(spur, path.nodes[index + 1]))
+                .collect::<Vec<_>>();
+
+            let spur_path = finder.shortest_path_where(graph, spur, to, |edge| {
+                !removed_nodes[edge.edge_id.to()] && !removed_edges.contains(&edge.edge_id)
+            });
+
+            if let Some(spur_path) = spur_path {
+                let mut nodes = root[..index].to_vec();
+                nodes.extend(path_nodes(&spur_path.parents, spur, to));
+
+                if !candidates.iter().any(|path| path.nodes == nodes) {
+                    candidates.push(WeightedPath {
+                        cost: root_cost + spur_path.distance,
+                        nodes,
+                    });
+                }
+            }
+
+            removed_nodes[spur] = true;
+            root_cost = root_cost + *graph[EdgeId::new_unchecked(spur, previous[index + 1])].cost();
+        }
+
+        let best = candidates
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cost.partial_cmp(&b.cost).unwrap())
+            .map(|(index, _)| index);
+
+        match best {
+            Some(index) => paths.push(candidates.swap_remove(index)),
+            None => break,
+        }
+    }
+
+    paths
+}
+
+fn path_nodes<G>(
+    parents: &Parents<G>,
+    from: NodeId<G::Key>,
+    to: NodeId<G::Key>,
+) -> Vec<NodeId<G::Key>>
+where
+    G: NodeAttribute,
+{
+    let mut nodes = vec![to];
+    nodes.extend(parents.iter_to(from, to));
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::yen;
+    use crate::algorithms::{BellmanFord, Dijkstra, dijkstra_to};
+    use crate::test::{digraph, id};
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    fn yen_example() -> AdjGraph<(), f64, true> {
+        // C = 0, D = 1, E = 2, F = 3, G = 4, H = 5
+        AdjGraph::<(), f64, true>::with_edges(
+            [
+                (0, 1, 3.0),
+                (0, 2, 2.0),
+                (1, 3, 4.0),
+                (2, 1, 1.0),
+                (2, 3, 2.0),
+                (2, 4, 3.0),
+                (3, 4, 2.0),
+                (3, 5, 1.0),
+                (4, 5, 2.0),
+            ],
+            6,
+        )
+    }
+
+    #[bench]
+    fn yen_example_dijkstra(b: &mut Bencher) {
+        let graph = yen_example();
+
+        b.iter(|| {
+            let paths = yen(&graph, id(0), id(5), 3, Dijkstra);
+
+            assert_eq!(
+                paths.iter().map(|path| path.cost).collect::<Vec<_>>(),
+                vec![5.0, 7.0, 8.0]
+            );
+            assert_eq!(paths[0].nodes, vec![id(0), id(2), id(3), id(5)]);
+            assert_eq!(paths[1].nodes, vec![id(0), id(2), id(4), id(5)]);
+            assert_eq!(paths[2].nodes, vec![id(0), id(1), id(3), id(5)]);
+        })
+    }
+
+    #[bench]
+    fn yen_example_exhausted(b: &mut Bencher) {
+        let graph = yen_example();
+
+        b.iter(|| {
+            // every simple path from C to H
+            let paths = yen(&graph, id(0), id(5), 10, BellmanFord);
+            assert_eq!(paths.len(), 7);
+        })
+    }
+
+    #[bench]
+    fn yen_g_1_2_di_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let paths = yen(&graph, id(0), id(1), 5, Dijkstra);
+            let expected = dijkstra_to(&graph, id(0), id(1)).unwrap();
+
+            assert_eq!(paths.len(), 5);
+            assert_eq!(paths[0].cost as f32, expected.distance as f32);
+
+            for (index, path) in paths.iter().enumerate() {
+                let cost = path.edge_ids().map(|edge_id| graph[edge_id]).sum::<f64>();
+                assert_le!((cost - path.cost).abs(), 1e-9);
+
+                let mut nodes = path.nodes.clone();
+                nodes.sort();
+                nodes.dedup();
+                assert_eq!(nodes.len(), path.nodes.len());
+
+                for other in &paths[..index] {
+                    assert_le!(other.cost, path.cost);
+                    assert_ne!(other.nodes, path.nodes);
+                }
+            }
+        })
+    }
+
+    #[bench]
+    fn yen_g_1_2_di_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+
+        b.iter(|| {
+            let paths = yen(&graph, id(0), id(1), 5, Dijkstra);
+
+            assert_eq!(paths.len(), 5);
+            assert_eq!(paths[0].cost as f32, 5.56283);
+            assert!(paths.windows(2).all(|pair| pair[0].cost <= pair[1].cost));
+        })
+    }
+}
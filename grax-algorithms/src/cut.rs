@@ -0,0 +1,162 @@
+use std::ops::{AddAssign, Sub};
+
+use grax_core::{
+    collections::{IndexEdge, NodeIter, VisitNodeMap},
+    edge::{
+        EdgeRef,
+        weight::{Capacity, Flow, ResidualCapacity, Reverse},
+    },
+    graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, NodeAttribute},
+    index::{EdgeId, NodeId},
+};
+
+/// Minimum cut separating the source from the sink
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinCut<C, G>
+where
+    G: NodeAttribute,
+{
+    /// Sum of the capacities of the cut edges, which equals the maximum flow
+    pub capacity: C,
+    /// Marks every node on the side of the source
+    pub partition: G::FixedNodeMap<bool>,
+    /// Saturated edges from the source side to the sink side
+    pub edge_ids: Vec<EdgeId<G::Key>>,
+}
+
+impl<C, G> MinCut<C, G>
+where
+    C: Default + PartialOrd + Copy + Sub<C, Output = C> + AddAssign,
+    G: NodeAttribute + NodeIter + EdgeIterAdjacent + EdgeIterAdjacentInbound + IndexEdge,
+    G::EdgeWeight: Flow<C> + Capacity<C> + Reverse,
+{
+    /// Extracts the cut from a graph carrying a maximum flow,
+    /// as left behind by any of the maximum flow algorithms.
+    /// The source side consists of all nodes reachable in the residual graph,
+    /// which also works if the residual edges are still present.
+    pub fn from_flow(graph: &G, source: NodeId<G::Key>) -> Self {
+        let mut partition = graph.visit_node_map();
+        let mut stack = vec![source];
+        partition.visit(source);
+
+        while let Some(node) = stack.pop() {
+            let forward = graph
+                .iter_adjacent_edges(node)
+                .filter(|EdgeRef { weight, .. }| weight.residual_capacity() > C::default())
+                .map(|EdgeRef { edge_id, .. }| edge_id.to());
+
+            // flow on an incoming edge can be pushed back
+            let backward = graph
+                .iter_inbound_edges(node)
+                .filter(|EdgeRef { weight, .. }| {
+                    !weight.is_reverse() && *weight.flow() > C::default()
+                })
+                .map(|EdgeRef { edge_id, .. }| edge_id.from());
+
+            for next in forward.chain(backward) {
+                if !partition.is_visited(next) {
+                    partition.visit(next);
+                    stack.push(next);
+                }
+            }
+        }
+
+        let mut capacity = C::default();
+        let mut edge_ids = Vec::new();
+
+        for node in partition.iter_visited() {
+            for EdgeRef { edge_id, weight } in graph.iter_adjacent_edges(node) {
+                if !weight.is_reverse() && !partition.is_visited(edge_id.to()) {
+                    capacity += *weight.capacity();
+                    edge_ids.push(edge_id);
+                }
+            }
+        }
+
+        Self {
+            capacity,
+            partition,
+            edge_ids,
+        }
+    }
+
+    pub fn is_source_side(&self, node_id: NodeId<G::Key>) -> bool {
+        self.partition.is_visited(node_id)
+    }
+}
+
+/// Minimum cut over all pairs of nodes in an undirected graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalMinCut<C, G>
+where
+    G: NodeAttribute,
+{
+    /// Sum of the costs of the edges between both sides
+    pub capacity: C,
+    /// Marks every node on one side of the cut
+    pub partition: G::FixedNodeMap<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    extern crate test;
+
+    use super::MinCut;
+    use crate::algorithms::{dinic, edmonds_karp, empty_flow, flow_adaptor, push_relabel};
+    use crate::test::{digraph, id};
+    use grax_core::edge::weight::{Capacity, Flow};
+    use grax_impl::*;
+    use more_asserts::assert_le;
+    use test::Bencher;
+
+    #[bench]
+    fn min_cut_fluss_edmonds_karp_adj_list(b: &mut Bencher) {
+        let graph: AdjGraph<_, _, true> = digraph("../data/Fluss.txt").unwrap();
+        let mut graph: AdjGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = edmonds_karp(&mut graph, id(0), id(7));
+            let cut = MinCut::from_flow(&graph, id(0));
+
+            assert_eq!(cut.capacity, total);
+            assert!(cut.is_source_side(id(0)));
+            assert!(!cut.is_source_side(id(7)));
+
+            for &edge_id in &cut.edge_ids {
+                let weight = &graph[edge_id];
+                assert_eq!(weight.flow(), weight.capacity());
+            }
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn min_cut_fluss2_dinic_csr_mat(b: &mut Bencher) {
+        let graph: CsrGraph<_, _, true> = digraph("../data/Fluss2.txt").unwrap();
+        let mut graph: CsrGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = dinic(&mut graph, id(0), id(7));
+            let cut = MinCut::from_flow(&graph, id(0));
+
+            assert_eq!(cut.capacity, total);
+            assert!(!cut.is_source_side(id(7)));
+            empty_flow(&mut graph);
+        })
+    }
+
+    #[bench]
+    fn min_cut_g_1_2_push_relabel_dense_mat(b: &mut Bencher) {
+        let graph: MatGraph<_, _, true> = digraph("../data/G_1_2.txt").unwrap();
+        let mut graph: MatGraph<_, _, true> = flow_adaptor(graph);
+
+        b.iter(|| {
+            let total = push_relabel(&mut graph, id(0), id(7));
+            let cut = MinCut::from_flow(&graph, id(0));
+
+            assert_le!((cut.capacity - total).abs(), 1e-9);
+            assert!(!cut.is_source_side(id(7)));
+            empty_flow(&mut graph);
+        })
+    }
+}
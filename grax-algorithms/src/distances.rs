@@ -25,3 +25,56 @@ where
         self.0[node_id].as_ref()
     }
 }
+
+/// Maps every pair of nodes to a value
+type NodePairMap<G, V> = <G as NodeAttribute>::FixedNodeMap<<G as NodeAttribute>::FixedNodeMap<V>>;
+
+/// Distances and predecessors between every pair of nodes
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllPairsDistances<C, G>
+where
+    C: Clone + Debug + PartialEq,
+    G: NodeAttribute,
+{
+    pub(crate) distances: NodePairMap<G, Option<C>>,
+    pub(crate) parents: NodePairMap<G, Option<NodeId<G::Key>>>,
+}
+
+impl<C, G> AllPairsDistances<C, G>
+where
+    C: Clone + Debug + PartialEq,
+    G: NodeAttribute,
+{
+    pub fn new(graph: &G) -> Self {
+        Self {
+            distances: graph.fixed_node_map(graph.fixed_node_map(None)),
+            parents: graph.fixed_node_map(graph.fixed_node_map(None)),
+        }
+    }
+
+    pub fn distance(&self, from: NodeId<G::Key>, to: NodeId<G::Key>) -> Option<&C> {
+        self.distances[from][to].as_ref()
+    }
+
+    /// Returns the predecessor of `to` on the shortest path starting at `from`
+    pub fn parent(&self, from: NodeId<G::Key>, to: NodeId<G::Key>) -> Option<NodeId<G::Key>> {
+        self.parents[from][to]
+    }
+
+    /// Returns the nodes of the shortest path including both ends
+    /// Returns none if `to` is not reachable from `from`
+    pub fn path(&self, from: NodeId<G::Key>, to: NodeId<G::Key>) -> Option<Vec<NodeId<G::Key>>> {
+        self.distance(from, to)?;
+
+        let mut path = vec![to];
+        let mut node = to;
+
+        while node != from {
+            node = self.parent(from, node)?;
+            path.push(node);
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
@@ -3,9 +3,11 @@
 #![feature(iter_array_chunks)]
 
 pub mod algorithms;
+pub mod cut;
 pub mod cycle;
 pub mod distances;
 pub mod flow;
+pub mod matching;
 pub mod parents;
 pub mod path;
 pub mod tree;
@@ -13,8 +15,10 @@ pub mod weight;
 
 pub mod prelude {
     pub use crate::algorithms::*;
+    pub use crate::cut::*;
     pub use crate::cycle::*;
     pub use crate::distances::*;
+    pub use crate::matching::*;
     pub use crate::parents::*;
     pub use crate::path::*;
     pub use crate::tree::*;
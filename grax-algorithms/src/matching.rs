@@ -0,0 +1,60 @@
+use grax_core::{
+    collections::{GetNode, NodeCount},
+    graph::NodeAttribute,
+    index::{EdgeId, NodeId},
+};
+
+/// Set of edges without common nodes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matching<G>
+where
+    G: NodeAttribute,
+{
+    /// Node matched with every node if any
+    pub mates: G::FixedNodeMap<Option<NodeId<G::Key>>>,
+    /// Every matched edge once
+    pub edge_ids: Vec<EdgeId<G::Key>>,
+}
+
+impl<G> Matching<G>
+where
+    G: NodeAttribute,
+{
+    /// Creates an empty matching
+    pub fn new(graph: &G) -> Self {
+        Self {
+            mates: graph.fixed_node_map(None),
+            edge_ids: Vec::new(),
+        }
+    }
+
+    pub fn mate(&self, node_id: NodeId<G::Key>) -> Option<NodeId<G::Key>> {
+        self.mates.node(node_id).and_then(|mate| *mate.weight)
+    }
+
+    pub fn is_matched(&self, node_id: NodeId<G::Key>) -> bool {
+        self.mate(node_id).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.edge_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edge_ids.is_empty()
+    }
+
+    pub fn is_perfect(&self) -> bool {
+        self.len() * 2 == self.mates.node_count()
+    }
+}
+
+/// Matching together with the sum of the costs of its edges
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedMatching<C, G>
+where
+    G: NodeAttribute,
+{
+    pub matching: Matching<G>,
+    pub cost: C,
+}
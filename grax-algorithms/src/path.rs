@@ -1,6 +1,10 @@
 use std::fmt::Debug;
 
-use grax_core::{graph::NodeAttribute, index::NodeId};
+use grax_core::{
+    collections::Keyed,
+    graph::NodeAttribute,
+    index::{EdgeId, NodeId},
+};
 
 use crate::{distances::Distances, parents::Parents};
 
@@ -26,3 +30,24 @@ where
     pub distances: Distances<C, G>,
     pub parents: Parents<G>,
 }
+
+/// A path given by its nodes in order together with its total cost
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedPath<C, G>
+where
+    G: Keyed,
+{
+    pub cost: C,
+    pub nodes: Vec<NodeId<G::Key>>,
+}
+
+impl<C, G> WeightedPath<C, G>
+where
+    G: Keyed,
+{
+    pub fn edge_ids(&self) -> impl Iterator<Item = EdgeId<G::Key>> + '_ {
+        self.nodes
+            .windows(2)
+            .map(|pair| EdgeId::new_unchecked(pair[0], pair[1]))
+    }
+}
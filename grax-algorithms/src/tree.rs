@@ -1,6 +1,11 @@
+use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::ops::AddAssign;
 
-use grax_core::{graph::NodeAttribute, index::NodeId};
+use grax_core::{
+    graph::NodeAttribute,
+    index::{Identifier, NodeId},
+};
 
 use crate::{distances::Distances, parents::Parents};
 
@@ -24,6 +29,95 @@ where
     pub cost: C,
 }
 
+/// Node which cannot be reached from the root of an arborescence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Unreachable<K: Identifier>(pub NodeId<K>);
+
+/// Root and cost of a single tree of a minimum spanning forest
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MsfTree<C, K: Identifier> {
+    pub root: NodeId<K>,
+    pub cost: C,
+}
+
+/// Minimum spanning forest of a graph, which is not required to be connected
+#[derive(Debug, Clone, PartialEq)]
+pub struct Msf<C, G>
+where
+    G: NodeAttribute,
+{
+    /// Component of every node, labelled from one in the order of the node ids like `bfs_scc`
+    pub components: G::FixedNodeMap<u32>,
+    /// Parents of the trees of all components, whose roots have no parent
+    pub parents: Parents<G>,
+    /// Minimum spanning tree of every component
+    pub trees: BTreeMap<u32, MsfTree<C, G::Key>>,
+}
+
+impl<C, G> Msf<C, G>
+where
+    C: Default + AddAssign + Copy,
+    G: NodeAttribute,
+{
+    /// Returns the total cost of all trees
+    pub fn cost(&self) -> C {
+        let mut total = C::default();
+        for tree in self.trees.values() {
+            total += tree.cost;
+        }
+        total
+    }
+}
+
+/// Cut tree of an undirected graph, where the minimum cut between two nodes
+/// equals the smallest cut on the path between them in the tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct GomoryHuTree<C, G>
+where
+    C: Clone + Debug + PartialEq,
+    G: NodeAttribute,
+{
+    pub tree: Tree<G>,
+    /// Minimum cut between every node and its parent
+    pub cuts: G::FixedNodeMap<C>,
+}
+
+impl<C, G> GomoryHuTree<C, G>
+where
+    C: Copy + Debug + PartialOrd,
+    G: NodeAttribute,
+{
+    /// Returns the minimum cut between both nodes
+    /// Returns none if both nodes are the same
+    pub fn min_cut(&self, from: NodeId<G::Key>, to: NodeId<G::Key>) -> Option<C> {
+        let parents = &self.tree.parents;
+        let ancestors = std::iter::once(to)
+            .chain(parents.iter(to))
+            .collect::<Vec<_>>();
+
+        let mut min = None;
+        let mut node = from;
+
+        while !ancestors.contains(&node) {
+            min = smaller(min, self.cuts[node]);
+            node = parents.parent(node)?;
+        }
+
+        for &ancestor in ancestors.iter().take_while(|&&ancestor| ancestor != node) {
+            min = smaller(min, self.cuts[ancestor]);
+        }
+
+        min
+    }
+}
+
+fn smaller<C: PartialOrd>(min: Option<C>, value: C) -> Option<C> {
+    match min {
+        Some(min) if min <= value => Some(min),
+        _ => Some(value),
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct PathTree<G>
 where
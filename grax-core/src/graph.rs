@@ -29,6 +29,7 @@ pub trait ImGraph:
     + EdgeIter
     + NodeIter
     + EdgeIterAdjacent
+    + EdgeIterAdjacentInbound
     + NodeIterAdjacent
     + EdgeAttribute
     + NodeAttribute
@@ -144,6 +145,20 @@ pub trait EdgeIterAdjacent: EdgeCollection + Keyed {
     fn iter_adjacent_edges(&self, node_id: NodeId<Self::Key>) -> Self::Edges<'_>;
 }
 
+pub trait EdgeIterAdjacentInbound: EdgeCollection + Keyed {
+    type InboundEdgeIds<'a>: Iterator<Item = EdgeId<Self::Key>> + 'a
+    where
+        Self: 'a;
+    type InboundEdges<'a>: Iterator<Item = EdgeRef<'a, Self::Key, Self::EdgeWeight>> + 'a
+    where
+        Self::EdgeWeight: 'a,
+        Self: 'a;
+
+    fn inbound_edge_ids(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdgeIds<'_>;
+    /// This returns an iterator over all edges pointing to the specified node in the graph.
+    fn iter_inbound_edges(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdges<'_>;
+}
+
 pub trait NodeIterAdjacentMut: NodeCollection + Keyed {
     type NodesMut<'a>: Iterator<Item = NodeMut<'a, Self::Key, Self::NodeWeight>> + 'a
     where
@@ -12,3 +12,13 @@ impl<T> Balance<T> for T {
         self
     }
 }
+
+pub trait Position<T> {
+    fn position(&self) -> &[T];
+}
+
+impl<T, const D: usize> Position<T> for [T; D] {
+    fn position(&self) -> &[T] {
+        self
+    }
+}
@@ -9,7 +9,7 @@ use grax_core::{
         GetEdgeMut, InsertEdge, Keyed, RemoveEdge,
     },
     edge::{Edge, EdgeMut, EdgeRef},
-    graph::{EdgeIterAdjacent, EdgeIterAdjacentMut},
+    graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, EdgeIterAdjacentMut},
     index::{EdgeId, NodeId},
 };
 use serde::{Deserialize, Serialize};
@@ -17,14 +17,71 @@ use serde::{Deserialize, Serialize};
 use super::EdgeStorage;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "AdjacencyLayout<W>")]
 pub struct AdjacencyList<W> {
     edges: Vec<Vec<Edge<usize, W>>>,
+    /// Sorted sources of the edges pointing to every node
+    #[serde(skip)]
+    inbound: Vec<Vec<usize>>,
+}
+
+/// Serialized form of the list, which leaves out the inbound sources
+#[derive(Deserialize)]
+#[serde(rename = "AdjacencyList")]
+struct AdjacencyLayout<W> {
+    edges: Vec<Vec<Edge<usize, W>>>,
+}
+
+impl<W> From<AdjacencyLayout<W>> for AdjacencyList<W> {
+    fn from(AdjacencyLayout { edges }: AdjacencyLayout<W>) -> Self {
+        let mut list = Self {
+            inbound: vec![Vec::new(); edges.len()],
+            edges,
+        };
+        list.rebuild_inbound();
+        list
+    }
+}
+
+impl<W> AdjacencyList<W> {
+    fn rebuild_inbound(&mut self) {
+        for sources in &mut self.inbound {
+            sources.clear();
+        }
+
+        // the rows are visited in order, so every list of sources stays sorted
+        for edge in self.edges.iter().flatten() {
+            if *edge.to() >= self.inbound.len() {
+                self.inbound.resize_with(*edge.to() + 1, Vec::new);
+            }
+            self.inbound[*edge.to()].push(*edge.from());
+        }
+    }
 }
 
 impl<W: Debug> AdjacencyList<W> {
     pub fn search(&self, from: usize, to: usize) -> Result<usize, usize> {
         self.edges[from].binary_search_by(|edge| edge.edge_id.to().cmp(&NodeId::new_unchecked(to)))
     }
+
+    fn insert_source(&mut self, from: usize, to: usize) {
+        if to >= self.inbound.len() {
+            self.inbound.resize_with(to + 1, Vec::new);
+        }
+
+        let sources = &mut self.inbound[to];
+        match sources.binary_search(&from) {
+            Ok(idx) | Err(idx) => sources.insert(idx, from),
+        }
+    }
+
+    fn remove_source(&mut self, from: usize, to: usize) {
+        if let Some(sources) = self.inbound.get_mut(to)
+            && let Ok(idx) = sources.binary_search(&from)
+        {
+            sources.remove(idx);
+        }
+    }
 }
 
 impl<W: Debug> Keyed for AdjacencyList<W> {
@@ -168,6 +225,33 @@ impl<W: Debug> EdgeIterAdjacent for AdjacencyList<W> {
     }
 }
 
+impl<W: Debug> EdgeIterAdjacentInbound for AdjacencyList<W> {
+    type InboundEdgeIds<'a>
+        = impl Iterator<Item = EdgeId<usize>> + 'a
+    where
+        Self: 'a;
+    type InboundEdges<'a>
+        = impl Iterator<Item = EdgeRef<'a, usize, W>> + 'a
+    where
+        W: 'a,
+        Self: 'a;
+
+    fn inbound_edge_ids(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdgeIds<'_> {
+        self.iter_inbound_edges(node_id).map(|edge| edge.edge_id)
+    }
+
+    fn iter_inbound_edges(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdges<'_> {
+        self.inbound
+            .get(*node_id)
+            .into_iter()
+            .flatten()
+            .filter_map(move |&from| {
+                let pos = self.search(from, *node_id).ok()?;
+                Some((&self.edges[from][pos]).into())
+            })
+    }
+}
+
 impl<W: Debug> EdgeIterAdjacentMut for AdjacencyList<W> {
     type EdgesMut<'a>
         = impl Iterator<Item = EdgeMut<'a, usize, W>> + 'a
@@ -198,6 +282,7 @@ impl<W: Debug> InsertEdge for AdjacencyList<W> {
         match self.search(from, to) {
             Ok(idx) | Err(idx) => self.edges[from].insert(idx, edge),
         }
+        self.insert_source(from, to);
 
         edge_id
     }
@@ -214,6 +299,7 @@ impl<W: Debug> RemoveEdge for AdjacencyList<W> {
     ) -> Option<Edge<Self::Key, Self::EdgeWeight>> {
         let (from, to) = edge_id.raw();
         if let Ok(pos) = self.search(from, to) {
+            self.remove_source(from, to);
             Some(self.edges[from].remove(pos))
         } else {
             None
@@ -221,21 +307,25 @@ impl<W: Debug> RemoveEdge for AdjacencyList<W> {
     }
 
     fn remove_inbound(&mut self, node_id: NodeId<Self::Key>) {
-        let mut to_remove = self
-            .edge_ids()
-            .filter(|edge_id| edge_id.to() == node_id)
-            .collect::<Vec<_>>();
-
-        to_remove.sort_unstable();
+        let sources = self
+            .inbound
+            .get_mut(*node_id)
+            .map(std::mem::take)
+            .unwrap_or_default();
 
-        for edge_id in to_remove {
-            // self.edges[*edge_id.from()].remove(*edge_id.to());
-            self.remove_edge(edge_id);
+        for from in sources {
+            if let Ok(pos) = self.search(from, *node_id) {
+                self.edges[from].remove(pos);
+            }
         }
     }
 
     fn remove_outbound(&mut self, node_id: NodeId<Self::Key>) {
-        self.edges[*node_id].clear()
+        let row = std::mem::take(&mut self.edges[*node_id]);
+
+        for edge in row {
+            self.remove_source(*node_id, *edge.to());
+        }
     }
 
     fn retain_edges<F>(&mut self, mut visit: F)
@@ -245,6 +335,7 @@ impl<W: Debug> RemoveEdge for AdjacencyList<W> {
         for row in &mut self.edges {
             row.retain(|edge| visit(edge.into()))
         }
+        self.rebuild_inbound();
     }
 }
 
@@ -263,12 +354,16 @@ impl<W: Debug + Clone + PartialEq> EdgeMap<usize, W> for AdjacencyList<W> {}
 
 impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for AdjacencyList<W> {
     fn new() -> Self {
-        Self { edges: Vec::new() }
+        Self {
+            edges: Vec::new(),
+            inbound: Vec::new(),
+        }
     }
 
     fn with_capacity(node_count: usize, _: usize) -> Self {
         Self {
             edges: Vec::with_capacity(node_count),
+            inbound: Vec::with_capacity(node_count),
         }
     }
 
@@ -285,11 +380,13 @@ impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for AdjacencyList<W> {
 
     fn clear(&mut self) {
         self.edges.clear();
+        self.inbound.clear();
     }
 
     fn allocate(&mut self, additional: usize) {
         let size = self.edges.len() + additional;
         self.edges.resize_with(size, || Vec::new());
+        self.inbound.resize_with(size, Vec::new);
     }
 }
 
@@ -297,7 +394,7 @@ impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for AdjacencyList<W> {
 mod test {
     use crate::edges::test::{
         edge_storage_adjacent, edge_storage_capacity, edge_storage_clear, edge_storage_count,
-        edge_storage_get, edge_storage_remove,
+        edge_storage_get, edge_storage_inbound, edge_storage_inbound_update, edge_storage_remove,
     };
 
     use super::AdjacencyList;
@@ -325,4 +422,12 @@ mod test {
     fn adj_edge_storage_adjacent() {
         edge_storage_adjacent::<AdjacencyList<f32>>()
     }
+    #[test]
+    fn adj_edge_storage_inbound() {
+        edge_storage_inbound::<AdjacencyList<f32>>()
+    }
+    #[test]
+    fn adj_edge_storage_inbound_update() {
+        edge_storage_inbound_update::<AdjacencyList<f32>>()
+    }
 }
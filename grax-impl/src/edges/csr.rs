@@ -10,7 +10,7 @@ use grax_core::{
         GetEdgeMut, InsertEdge, Keyed, RemoveEdge,
     },
     edge::{Edge, EdgeMut, EdgeRef},
-    graph::{EdgeIterAdjacent, EdgeIterAdjacentMut},
+    graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, EdgeIterAdjacentMut},
     index::{EdgeId, NodeId},
 };
 use more_asserts::assert_lt;
@@ -29,6 +29,7 @@ impl<W: Debug> CsrMatrixIterator<W> {
         let CsrMatrix {
             mut row_offsets,
             edges,
+            ..
         } = matrix;
 
         row_offsets.reverse();
@@ -91,9 +92,68 @@ struct RowOffset {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "CsrLayout<W>")]
 pub struct CsrMatrix<W> {
     row_offsets: Vec<RowOffset>,
     edges: Vec<Edge<usize, W>>,
+    /// Offsets of the columns into the sources, which are sorted in every column
+    #[serde(skip)]
+    col_offsets: Vec<RowOffset>,
+    #[serde(skip)]
+    sources: Vec<usize>,
+}
+
+/// Serialized form of the matrix, which leaves out the columns
+#[derive(Deserialize)]
+#[serde(rename = "CsrMatrix")]
+struct CsrLayout<W> {
+    row_offsets: Vec<RowOffset>,
+    edges: Vec<Edge<usize, W>>,
+}
+
+impl<W> From<CsrLayout<W>> for CsrMatrix<W> {
+    fn from(CsrLayout { row_offsets, edges }: CsrLayout<W>) -> Self {
+        let (col_offsets, sources) = columns(&edges, row_offsets.len());
+
+        Self {
+            row_offsets,
+            edges,
+            col_offsets,
+            sources,
+        }
+    }
+}
+
+/// Compressed sparse columns of the edges, which are sorted by rows
+fn columns<W>(edges: &[Edge<usize, W>], col_count: usize) -> (Vec<RowOffset>, Vec<usize>) {
+    let col_count = edges
+        .iter()
+        .map(|edge| *edge.to() + 1)
+        .max()
+        .map_or(col_count, |count| count.max(col_count));
+
+    let mut col_offsets = vec![RowOffset { start: 0, end: 0 }; col_count];
+
+    for edge in edges {
+        col_offsets[*edge.to()].end += 1;
+    }
+
+    let mut start = 0;
+    for offset in &mut col_offsets {
+        let len = offset.end;
+        *offset = RowOffset { start, end: start };
+        start += len;
+    }
+
+    // the rows are visited in order, so every column stays sorted
+    let mut sources = vec![0; edges.len()];
+    for edge in edges {
+        let offset = &mut col_offsets[*edge.to()];
+        sources[offset.end] = *edge.from();
+        offset.end += 1;
+    }
+
+    (col_offsets, sources)
 }
 
 impl<W: Debug> CsrMatrix<W> {
@@ -126,10 +186,56 @@ impl<W: Debug> CsrMatrix<W> {
     }
 
     pub fn col(&self, col: usize) -> impl Iterator<Item = EdgeRef<'_, usize, W>> {
-        self.edges
-            .iter()
-            .filter(move |node| node.to() == NodeId::new_unchecked(col))
-            .map(Into::into)
+        self.col_offsets
+            .get(col)
+            .into_iter()
+            .flat_map(move |offset| {
+                self.sources[offset.start..offset.end]
+                    .iter()
+                    .filter_map(move |&from| {
+                        let to = NodeId::new_unchecked(col);
+                        self.edge(EdgeId::new_unchecked(NodeId::new_unchecked(from), to))
+                    })
+            })
+    }
+
+    fn insert_source(&mut self, from: usize, to: usize) {
+        if to >= self.col_offsets.len() {
+            let end = self.sources.len();
+            self.col_offsets
+                .resize(to + 1, RowOffset { start: end, end });
+        }
+
+        let offset = &mut self.col_offsets[to];
+
+        let index = match self.sources[offset.start..offset.end].binary_search(&from) {
+            Ok(index) | Err(index) => index + offset.start,
+        };
+
+        offset.end += 1;
+
+        self.sources.insert(index, from);
+
+        for offset in &mut self.col_offsets[to + 1..] {
+            offset.start += 1;
+            offset.end += 1;
+        }
+    }
+
+    fn remove_source(&mut self, from: usize, to: usize) {
+        if let Some(offset) = self.col_offsets.get_mut(to)
+            && let Ok(relative) = self.sources[offset.start..offset.end].binary_search(&from)
+        {
+            offset.end -= 1;
+            let pos = relative + offset.start;
+
+            for offset in &mut self.col_offsets[to + 1..] {
+                offset.start -= 1;
+                offset.end -= 1;
+            }
+
+            self.sources.remove(pos);
+        }
     }
 
     pub fn row(&self, row: usize) -> impl Iterator<Item = EdgeRef<'_, usize, W>> {
@@ -272,6 +378,8 @@ impl<W: Debug> InsertEdge for CsrMatrix<W> {
             offset.end += 1;
         }
 
+        self.insert_source(*from, *to);
+
         edge_id
     }
 
@@ -298,6 +406,8 @@ impl<W: Debug> RemoveEdge for CsrMatrix<W> {
                     offset.end -= 1;
                 }
 
+                self.remove_source(from, to);
+
                 Some(self.edges.remove(pos))
             } else {
                 None
@@ -308,10 +418,7 @@ impl<W: Debug> RemoveEdge for CsrMatrix<W> {
     }
 
     fn remove_inbound(&mut self, node_id: NodeId<Self::Key>) {
-        let to_remove = self
-            .edge_ids()
-            .filter(|edge_id| edge_id.to() == node_id)
-            .collect::<Vec<_>>();
+        let to_remove = self.inbound_edge_ids(node_id).collect::<Vec<_>>();
 
         for edge_id in to_remove {
             self.remove_edge(edge_id);
@@ -328,6 +435,14 @@ impl<W: Debug> RemoveEdge for CsrMatrix<W> {
                 offset.start -= removed;
                 offset.end -= removed;
             }
+            for to in self.edges[offset.start..offset.end]
+                .iter()
+                .map(|edge| *edge.to())
+                .collect::<Vec<_>>()
+            {
+                self.remove_source(*node_id, to);
+            }
+
             self.edges.drain(offset.start..offset.end);
         }
     }
@@ -363,6 +478,8 @@ impl<W: Debug> RemoveEdge for CsrMatrix<W> {
                 }
             }
         }
+
+        (self.col_offsets, self.sources) = columns(&self.edges, self.col_offsets.len());
     }
 }
 
@@ -417,6 +534,26 @@ impl<W: Debug> EdgeIterAdjacent for CsrMatrix<W> {
     }
 }
 
+impl<W: Debug> EdgeIterAdjacentInbound for CsrMatrix<W> {
+    type InboundEdgeIds<'a>
+        = impl Iterator<Item = EdgeId<usize>> + 'a
+    where
+        Self: 'a;
+    type InboundEdges<'a>
+        = impl Iterator<Item = EdgeRef<'a, usize, W>> + 'a
+    where
+        W: 'a,
+        Self: 'a;
+
+    fn inbound_edge_ids(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdgeIds<'_> {
+        self.iter_inbound_edges(node_id).map(|edge| edge.edge_id)
+    }
+
+    fn iter_inbound_edges(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdges<'_> {
+        self.col(*node_id)
+    }
+}
+
 impl<W: Debug> EdgeIterAdjacentMut for CsrMatrix<W> {
     type EdgesMut<'a>
         = impl Iterator<Item = EdgeMut<'a, usize, W>> + 'a
@@ -438,6 +575,8 @@ impl<W: Debug + Clone + PartialEq + Send + Sync> EdgeStorage<usize, W> for CsrMa
         Self {
             edges: Vec::new(),
             row_offsets: vec![RowOffset { start: 0, end: 0 }],
+            col_offsets: vec![RowOffset { start: 0, end: 0 }],
+            sources: Vec::new(),
         }
     }
 
@@ -445,6 +584,8 @@ impl<W: Debug + Clone + PartialEq + Send + Sync> EdgeStorage<usize, W> for CsrMa
         Self {
             edges: Vec::with_capacity(edge_count),
             row_offsets: vec![RowOffset { start: 0, end: 0 }],
+            col_offsets: vec![RowOffset { start: 0, end: 0 }],
+            sources: Vec::with_capacity(edge_count),
         }
     }
 
@@ -486,12 +627,21 @@ impl<W: Debug + Clone + PartialEq + Send + Sync> EdgeStorage<usize, W> for CsrMa
             }
         }
 
-        Self { row_offsets, edges }
+        let (col_offsets, sources) = columns(&edges, node_count);
+
+        Self {
+            row_offsets,
+            edges,
+            col_offsets,
+            sources,
+        }
     }
 
     fn clear(&mut self) {
         self.edges.clear();
         self.row_offsets.fill(RowOffset { start: 0, end: 0 });
+        self.sources.clear();
+        self.col_offsets.fill(RowOffset { start: 0, end: 0 });
     }
 
     fn allocate(&mut self, additional: usize) {
@@ -503,6 +653,11 @@ impl<W: Debug + Clone + PartialEq + Send + Sync> EdgeStorage<usize, W> for CsrMa
                 end: offset.end,
             });
         }
+
+        let end = self.sources.len();
+        let col_count = self.col_offsets.len().max(self.row_offsets.len());
+        self.col_offsets
+            .resize(col_count, RowOffset { start: end, end });
     }
 }
 
@@ -510,7 +665,7 @@ impl<W: Debug + Clone + PartialEq + Send + Sync> EdgeStorage<usize, W> for CsrMa
 mod test {
     use crate::edges::test::{
         edge_storage_adjacent, edge_storage_capacity, edge_storage_clear, edge_storage_count,
-        edge_storage_get, edge_storage_remove,
+        edge_storage_get, edge_storage_inbound, edge_storage_inbound_update, edge_storage_remove,
     };
 
     use super::CsrMatrix;
@@ -538,4 +693,12 @@ mod test {
     fn csr_edge_storage_adjacent() {
         edge_storage_adjacent::<CsrMatrix<f32>>()
     }
+    #[test]
+    fn csr_edge_storage_inbound() {
+        edge_storage_inbound::<CsrMatrix<f32>>()
+    }
+    #[test]
+    fn csr_edge_storage_inbound_update() {
+        edge_storage_inbound_update::<CsrMatrix<f32>>()
+    }
 }
@@ -1,6 +1,6 @@
 use std::{
     fmt::Debug,
-    ops::{Index, IndexMut, Rem},
+    ops::{Index, IndexMut},
 };
 
 use grax_core::{
@@ -12,15 +12,68 @@ use grax_core::{
 };
 use serde::{Deserialize, Serialize};
 
+/// Edge map over a fixed set of edges, which are sorted by their ids,
+/// so that the edges of a node are found by their offset and a binary search
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct FixedEdgeVec<V> {
     vec: Vec<V>,
-    node_count: usize,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
 }
 
-impl<V: Debug> FixedEdgeVec<V> {
-    pub fn new(vec: Vec<V>, node_count: usize) -> Self {
-        Self { vec, node_count }
+impl<V: Debug + Clone> FixedEdgeVec<V> {
+    pub fn new(
+        node_count: usize,
+        edge_ids: impl IntoIterator<Item = EdgeId<usize>>,
+        fill: V,
+    ) -> Self {
+        let mut edge_ids = edge_ids
+            .into_iter()
+            .map(|edge_id| edge_id.raw())
+            .collect::<Vec<_>>();
+        edge_ids.sort_unstable();
+
+        // removed nodes of stable storages leave gaps in the node ids
+        let node_count = edge_ids
+            .last()
+            .map_or(node_count, |&(from, _)| node_count.max(from + 1));
+        let mut offsets = vec![0; node_count + 1];
+        for &(from, _) in &edge_ids {
+            offsets[from + 1] += 1;
+        }
+        for node in 0..node_count {
+            offsets[node + 1] += offsets[node];
+        }
+
+        let targets = edge_ids.into_iter().map(|(_, to)| to).collect::<Vec<_>>();
+        let vec = vec![fill; targets.len()];
+
+        Self {
+            vec,
+            offsets,
+            targets,
+        }
+    }
+}
+
+impl<V> FixedEdgeVec<V> {
+    fn key(&self, edge_id: EdgeId<usize>) -> Option<usize> {
+        let (from, to) = edge_id.raw();
+        let start = *self.offsets.get(from)?;
+        let end = *self.offsets.get(from + 1)?;
+
+        self.targets[start..end]
+            .binary_search(&to)
+            .ok()
+            .map(|position| start + position)
+    }
+
+    fn edge_id(&self, key: usize) -> EdgeId<usize> {
+        // the first node whose edges end after the key
+        let from = self.offsets.partition_point(|&offset| offset <= key) - 1;
+        let from = NodeId::new_unchecked(from);
+        let to = NodeId::new_unchecked(self.targets[key]);
+        EdgeId::new_unchecked(from, to)
     }
 }
 
@@ -57,11 +110,8 @@ impl<V: Debug> IndexMut<EdgeId<usize>> for FixedEdgeVec<V> {
 
 impl<V: Debug> GetEdge for FixedEdgeVec<V> {
     fn edge(&self, edge_id: EdgeId<Self::Key>) -> Option<EdgeRef<Self::Key, Self::EdgeWeight>> {
-        let (from, to) = edge_id.raw();
-        let key = from * self.node_count + to;
-        self.vec
-            .get(key)
-            .map(|weight| EdgeRef::new(edge_id, weight))
+        self.key(edge_id)
+            .map(|key| EdgeRef::new(edge_id, &self.vec[key]))
     }
 }
 
@@ -70,11 +120,8 @@ impl<V: Debug> GetEdgeMut for FixedEdgeVec<V> {
         &mut self,
         edge_id: EdgeId<Self::Key>,
     ) -> Option<grax_core::prelude::EdgeMut<Self::Key, Self::EdgeWeight>> {
-        let (from, to) = edge_id.raw();
-        let key = from * self.node_count + to;
-        self.vec
-            .get_mut(key)
-            .map(|weight| EdgeMut::new(edge_id, weight))
+        self.key(edge_id)
+            .map(|key| EdgeMut::new(edge_id, &mut self.vec[key]))
     }
 }
 
@@ -89,20 +136,20 @@ impl<V: Debug> EdgeIter for FixedEdgeVec<V> {
         Self: 'a;
 
     fn iter_edges(&self) -> Self::Edges<'_> {
-        self.vec.iter().enumerate().map(|(key, weight)| {
-            let from = NodeId::new_unchecked(key.div_floor(self.node_count));
-            let to = NodeId::new_unchecked(key.rem(self.node_count));
-            let edge_id = EdgeId::new_unchecked(from, to);
-            EdgeRef::new(edge_id, weight)
-        })
+        self.edge_ids()
+            .zip(&self.vec)
+            .map(|(edge_id, weight)| EdgeRef::new(edge_id, weight))
     }
 
     fn edge_ids(&self) -> Self::EdgeIds<'_> {
-        (0..self.vec.len()).map(|key| {
-            let from = NodeId::new_unchecked(key.div_floor(self.node_count));
-            let to = NodeId::new_unchecked(key.rem(self.node_count));
-            EdgeId::new_unchecked(from, to)
-        })
+        self.offsets
+            .windows(2)
+            .enumerate()
+            .flat_map(|(from, range)| {
+                self.targets[range[0]..range[1]].iter().map(move |&to| {
+                    EdgeId::new_unchecked(NodeId::new_unchecked(from), NodeId::new_unchecked(to))
+                })
+            })
     }
 }
 
@@ -113,13 +160,59 @@ impl<V: Debug> EdgeIterMut for FixedEdgeVec<V> {
         Self: 'a;
 
     fn iter_edges_mut(&mut self) -> Self::EdgesMut<'_> {
-        self.vec.iter_mut().enumerate().map(|(key, weight)| {
-            let from = NodeId::new_unchecked(key.div_floor(self.node_count));
-            let to = NodeId::new_unchecked(key.rem(self.node_count));
-            let edge_id = EdgeId::new_unchecked(from, to);
-            EdgeMut::new(edge_id, weight)
-        })
+        let edge_ids = (0..self.vec.len())
+            .map(|key| self.edge_id(key))
+            .collect::<Vec<_>>();
+
+        edge_ids
+            .into_iter()
+            .zip(self.vec.iter_mut())
+            .map(|(edge_id, weight)| EdgeMut::new(edge_id, weight))
     }
 }
 
 impl<V: Debug + Clone + PartialEq> FixedEdgeMap<usize, V> for FixedEdgeVec<V> {}
+
+#[cfg(test)]
+mod test {
+    use grax_core::{
+        collections::{EdgeIter, GetEdge},
+        index::{EdgeId, NodeId},
+    };
+
+    use super::FixedEdgeVec;
+
+    fn edge_id(from: usize, to: usize) -> EdgeId<usize> {
+        EdgeId::new_unchecked(NodeId::new_unchecked(from), NodeId::new_unchecked(to))
+    }
+
+    #[test]
+    fn fixed_edge_vec_get() {
+        let edge_ids = [edge_id(3, 0), edge_id(0, 2), edge_id(0, 1), edge_id(2, 3)];
+        let mut map = FixedEdgeVec::new(4, edge_ids, 0);
+
+        map[edge_id(3, 0)] = 1;
+        map[edge_id(0, 2)] = 2;
+
+        assert_eq!(map[edge_id(3, 0)], 1);
+        assert_eq!(map[edge_id(0, 2)], 2);
+        assert_eq!(map[edge_id(0, 1)], 0);
+        assert!(map.edge(edge_id(1, 0)).is_none());
+        assert!(map.edge(edge_id(7, 0)).is_none());
+    }
+
+    #[test]
+    fn fixed_edge_vec_iter() {
+        let edge_ids = [edge_id(3, 0), edge_id(0, 2), edge_id(0, 1), edge_id(2, 3)];
+        let map = FixedEdgeVec::new(4, edge_ids, 0);
+
+        let mut expected = edge_ids.to_vec();
+        expected.sort();
+
+        assert_eq!(map.edge_ids().collect::<Vec<_>>(), expected);
+        assert!(
+            map.iter_edges()
+                .all(|edge| map.edge(edge.edge_id).is_some())
+        );
+    }
+}
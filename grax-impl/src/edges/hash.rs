@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::Debug,
     ops::{Index, IndexMut},
 };
@@ -10,15 +10,58 @@ use grax_core::{
         GetEdgeMut, InsertEdge, Keyed, RemoveEdge,
     },
     edge::{Edge, EdgeMut, EdgeRef},
-    graph::{EdgeIterAdjacent, EdgeIterAdjacentMut},
+    graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, EdgeIterAdjacentMut},
     index::{EdgeId, NodeId},
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 use super::EdgeStorage;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct HashStorage<W>(HashMap<EdgeId<usize>, W>);
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "HashLayout<W>")]
+pub struct HashStorage<W> {
+    edges: HashMap<EdgeId<usize>, W>,
+    /// Sources of the edges pointing to every node, which has any
+    inbound: HashMap<usize, HashSet<usize>>,
+}
+
+/// Serialized form of the storage, a newtype over the edges
+#[derive(Deserialize)]
+#[serde(rename = "HashStorage")]
+struct HashLayout<W>(HashMap<EdgeId<usize>, W>);
+
+impl<W> From<HashLayout<W>> for HashStorage<W> {
+    fn from(HashLayout(edges): HashLayout<W>) -> Self {
+        let mut inbound = HashMap::<usize, HashSet<usize>>::new();
+        for edge_id in edges.keys() {
+            inbound
+                .entry(*edge_id.to())
+                .or_default()
+                .insert(*edge_id.from());
+        }
+
+        Self { edges, inbound }
+    }
+}
+
+impl<W: Serialize> Serialize for HashStorage<W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("HashStorage", &self.edges)
+    }
+}
+
+impl<W> HashStorage<W> {
+    fn remove_source(&mut self, from: usize, to: usize) {
+        if let Some(sources) = self.inbound.get_mut(&to) {
+            sources.remove(&from);
+
+            // keeps storages with the same edges equal
+            if sources.is_empty() {
+                self.inbound.remove(&to);
+            }
+        }
+    }
+}
 
 impl<W: Debug> Keyed for HashStorage<W> {
     type Key = usize;
@@ -28,13 +71,13 @@ impl<W: Debug> EdgeCollection for HashStorage<W> {
     type EdgeWeight = W;
 
     fn edges_capacity(&self) -> usize {
-        self.0.capacity()
+        self.edges.capacity()
     }
 }
 
 impl<W: Debug> EdgeCount for HashStorage<W> {
     fn edge_count(&self) -> usize {
-        self.0.len()
+        self.edges.len()
     }
 }
 
@@ -54,7 +97,7 @@ impl<W: Debug> IndexMut<EdgeId<usize>> for HashStorage<W> {
 
 impl<W: Debug> GetEdge for HashStorage<W> {
     fn edge(&self, edge_id: EdgeId<Self::Key>) -> Option<EdgeRef<Self::Key, Self::EdgeWeight>> {
-        self.0
+        self.edges
             .get(&edge_id)
             .map(|weight| EdgeRef::new(edge_id, weight))
     }
@@ -65,7 +108,7 @@ impl<W: Debug> GetEdge for HashStorage<W> {
     //     to: NodeId<Self::Key>,
     // ) -> Option<EdgeId<Self::Key>> {
     //     let edge_id = EdgeId::new_unchecked(from, to);
-    //     if self.0.contains_key(&edge_id) {
+    //     if self.edges.contains_key(&edge_id) {
     //         Some(edge_id)
     //     } else {
     //         None
@@ -78,7 +121,7 @@ impl<W: Debug> GetEdgeMut for HashStorage<W> {
         &mut self,
         edge_id: EdgeId<Self::Key>,
     ) -> Option<EdgeMut<Self::Key, Self::EdgeWeight>> {
-        self.0
+        self.edges
             .get_mut(&edge_id)
             .map(|weight| EdgeMut::new(edge_id, weight))
     }
@@ -92,24 +135,22 @@ impl<W: Debug> InsertEdge for HashStorage<W> {
         weight: Self::EdgeWeight,
     ) -> EdgeId<Self::Key> {
         let edge_id = EdgeId::new_unchecked(from, to);
-        self.0.insert(edge_id, weight);
+        self.edges.insert(edge_id, weight);
+        self.inbound.entry(*to).or_default().insert(*from);
         edge_id
     }
 
     fn reserve_edges(&mut self, additional: usize) {
-        self.0.reserve(additional)
+        self.edges.reserve(additional)
     }
 
     fn extend_edges(
         &mut self,
         edges: impl IntoIterator<Item = (NodeId<Self::Key>, NodeId<Self::Key>, Self::EdgeWeight)>,
     ) {
-        let edges = edges.into_iter().map(|(from, to, weight)| {
-            let edge_id = EdgeId::new_unchecked(from, to);
-            (edge_id, weight)
-        });
-
-        self.0.extend(edges);
+        for (from, to, weight) in edges {
+            self.insert_edge(from, to, weight);
+        }
     }
 }
 
@@ -118,27 +159,23 @@ impl<W: Debug> RemoveEdge for HashStorage<W> {
         &mut self,
         edge_id: EdgeId<Self::Key>,
     ) -> Option<Edge<Self::Key, Self::EdgeWeight>> {
-        self.0
-            .remove(&edge_id)
-            .map(|weight| Edge::new(edge_id, weight))
+        let weight = self.edges.remove(&edge_id)?;
+        self.remove_source(*edge_id.from(), *edge_id.to());
+        Some(Edge::new(edge_id, weight))
     }
 
     fn remove_inbound(&mut self, node_id: NodeId<Self::Key>) {
-        let to_remove = self
-            .0
-            .keys()
-            .filter(|edge_id| edge_id.to() == node_id)
-            .cloned()
-            .collect::<Vec<_>>();
+        let sources = self.inbound.remove(&*node_id).unwrap_or_default();
 
-        for edge_id in to_remove {
-            self.remove_edge(edge_id);
+        for from in sources {
+            let edge_id = EdgeId::new_unchecked(NodeId::new_unchecked(from), node_id);
+            self.edges.remove(&edge_id);
         }
     }
 
     fn remove_outbound(&mut self, node_id: NodeId<Self::Key>) {
         let to_remove = self
-            .0
+            .edges
             .keys()
             .filter(|edge_id| edge_id.from() == node_id)
             .cloned()
@@ -153,8 +190,20 @@ impl<W: Debug> RemoveEdge for HashStorage<W> {
     where
         F: FnMut(EdgeRef<Self::Key, Self::EdgeWeight>) -> bool,
     {
-        self.0
-            .retain(|&edge_id, weight| visit(EdgeRef { edge_id, weight }))
+        let inbound = &mut self.inbound;
+
+        self.edges.retain(|&edge_id, weight| {
+            let retain = visit(EdgeRef { edge_id, weight });
+
+            if !retain && let Some(sources) = inbound.get_mut(&*edge_id.to()) {
+                sources.remove(&*edge_id.from());
+                if sources.is_empty() {
+                    inbound.remove(&*edge_id.to());
+                }
+            }
+
+            retain
+        })
     }
 }
 
@@ -170,11 +219,11 @@ impl<W: Debug> EdgeIter for HashStorage<W> {
         Self: 'a;
 
     fn edge_ids(&self) -> Self::EdgeIds<'_> {
-        self.0.keys().cloned()
+        self.edges.keys().cloned()
     }
 
     fn iter_edges(&self) -> Self::Edges<'_> {
-        self.0
+        self.edges
             .iter()
             .map(|(edge_id, weight)| EdgeRef::new(*edge_id, weight))
     }
@@ -188,7 +237,7 @@ impl<W: Debug> EdgeIterMut for HashStorage<W> {
         Self: 'a;
 
     fn iter_edges_mut(&mut self) -> Self::EdgesMut<'_> {
-        self.0
+        self.edges
             .iter_mut()
             .map(|(edge_id, weight)| EdgeMut::new(*edge_id, weight))
     }
@@ -206,14 +255,14 @@ impl<W: Debug> EdgeIterAdjacent for HashStorage<W> {
         Self: 'a;
 
     fn adjacent_edge_ids(&self, node_id: NodeId<Self::Key>) -> Self::EdgeIds<'_> {
-        self.0
+        self.edges
             .keys()
             .filter(move |edge_id| edge_id.from() == node_id)
             .cloned()
     }
 
     fn iter_adjacent_edges(&self, node_id: NodeId<Self::Key>) -> Self::Edges<'_> {
-        self.0.iter().filter_map(move |(edge_id, weight)| {
+        self.edges.iter().filter_map(move |(edge_id, weight)| {
             if edge_id.from() == node_id {
                 Some(EdgeRef::new(*edge_id, weight))
             } else {
@@ -223,6 +272,33 @@ impl<W: Debug> EdgeIterAdjacent for HashStorage<W> {
     }
 }
 
+impl<W: Debug> EdgeIterAdjacentInbound for HashStorage<W> {
+    type InboundEdgeIds<'a>
+        = impl Iterator<Item = EdgeId<usize>> + 'a
+    where
+        Self: 'a;
+    type InboundEdges<'a>
+        = impl Iterator<Item = EdgeRef<'a, usize, W>> + 'a
+    where
+        W: 'a,
+        Self: 'a;
+
+    fn inbound_edge_ids(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdgeIds<'_> {
+        self.iter_inbound_edges(node_id).map(|edge| edge.edge_id)
+    }
+
+    fn iter_inbound_edges(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdges<'_> {
+        self.inbound
+            .get(&*node_id)
+            .into_iter()
+            .flatten()
+            .filter_map(move |&from| {
+                let edge_id = EdgeId::new_unchecked(NodeId::new_unchecked(from), node_id);
+                self.edge(edge_id)
+            })
+    }
+}
+
 impl<W: Debug> EdgeIterAdjacentMut for HashStorage<W> {
     type EdgesMut<'a>
         = impl Iterator<Item = EdgeMut<'a, usize, W>> + 'a
@@ -231,7 +307,7 @@ impl<W: Debug> EdgeIterAdjacentMut for HashStorage<W> {
         Self: 'a;
 
     fn iter_adjacent_edges_mut(&mut self, node_id: NodeId<Self::Key>) -> Self::EdgesMut<'_> {
-        self.0.iter_mut().filter_map(move |(edge_id, weight)| {
+        self.edges.iter_mut().filter_map(move |(edge_id, weight)| {
             if edge_id.from() == node_id {
                 Some(EdgeMut::new(*edge_id, weight))
             } else {
@@ -246,7 +322,7 @@ impl<W: Debug> IntoIterator for HashStorage<W> {
     type Item = Edge<usize, W>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0
+        self.edges
             .into_iter()
             .map(|(edge_id, weight)| Edge::new(edge_id, weight))
     }
@@ -258,11 +334,17 @@ impl<W: Debug + Clone + PartialEq> EdgeMap<usize, W> for HashStorage<W> {}
 
 impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for HashStorage<W> {
     fn new() -> Self {
-        Self(HashMap::new())
+        Self {
+            edges: HashMap::new(),
+            inbound: HashMap::new(),
+        }
     }
 
-    fn with_capacity(_: usize, edge_count: usize) -> Self {
-        Self(HashMap::with_capacity(edge_count))
+    fn with_capacity(node_count: usize, edge_count: usize) -> Self {
+        Self {
+            edges: HashMap::with_capacity(edge_count),
+            inbound: HashMap::with_capacity(node_count),
+        }
     }
 
     fn with_edges(
@@ -276,7 +358,8 @@ impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for HashStorage<W> {
     }
 
     fn clear(&mut self) {
-        self.0.clear()
+        self.edges.clear();
+        self.inbound.clear();
     }
 
     fn allocate(&mut self, _: usize) {}
@@ -286,7 +369,7 @@ impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for HashStorage<W> {
 mod test {
     use crate::edges::test::{
         edge_storage_adjacent, edge_storage_capacity, edge_storage_clear, edge_storage_count,
-        edge_storage_get, edge_storage_remove,
+        edge_storage_get, edge_storage_inbound, edge_storage_inbound_update, edge_storage_remove,
     };
 
     use super::HashStorage;
@@ -314,4 +397,12 @@ mod test {
     fn hash_edge_storage_adjacent() {
         edge_storage_adjacent::<HashStorage<f32>>()
     }
+    #[test]
+    fn hash_edge_storage_inbound() {
+        edge_storage_inbound::<HashStorage<f32>>()
+    }
+    #[test]
+    fn hash_edge_storage_inbound_update() {
+        edge_storage_inbound_update::<HashStorage<f32>>()
+    }
 }
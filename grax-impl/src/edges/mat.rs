@@ -9,7 +9,7 @@ use grax_core::{
         GetEdgeMut, InsertEdge, Keyed, RemoveEdge,
     },
     edge::{Edge, EdgeMut, EdgeRef},
-    graph::{EdgeIterAdjacent, EdgeIterAdjacentMut},
+    graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, EdgeIterAdjacentMut},
     index::{EdgeId, NodeId},
 };
 use stable_vec::StableVec;
@@ -19,17 +19,31 @@ use super::EdgeStorage;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AdjacencyMatrix<W> {
     edges: Vec<StableVec<Edge<usize, W>>>,
+    /// Sorted sources of the edges pointing to every node
+    inbound: Vec<Vec<usize>>,
 }
 
 impl<W: Debug> AdjacencyMatrix<W> {
     pub fn col(&self, col: usize) -> impl Iterator<Item = EdgeRef<'_, usize, W>> {
-        self.edges.iter().flat_map(move |edges| {
-            edges.iter().filter_map(
-                move |(to, edge)| {
-                    if to == col { Some(edge.into()) } else { None }
-                },
-            )
-        })
+        self.inbound
+            .get(col)
+            .into_iter()
+            .flatten()
+            .filter_map(move |&from| self.edges[from].get(col).map(Into::into))
+    }
+
+    fn insert_source(&mut self, from: usize, to: usize) {
+        let sources = &mut self.inbound[to];
+        if let Err(idx) = sources.binary_search(&from) {
+            sources.insert(idx, from);
+        }
+    }
+
+    fn remove_source(&mut self, from: usize, to: usize) {
+        let sources = &mut self.inbound[to];
+        if let Ok(idx) = sources.binary_search(&from) {
+            sources.remove(idx);
+        }
     }
 
     /// Returns the number of non-zero elements in the matrix
@@ -126,6 +140,7 @@ impl<W: Debug> InsertEdge for AdjacencyMatrix<W> {
         let edge_id = EdgeId::new_unchecked(from, to);
         let edge = Edge::new(edge_id, weight);
         self.edges[*from].insert(*to, edge);
+        self.insert_source(*from, *to);
         edge_id
     }
 
@@ -140,20 +155,23 @@ impl<W: Debug> RemoveEdge for AdjacencyMatrix<W> {
         edge_id: EdgeId<Self::Key>,
     ) -> Option<Edge<Self::Key, Self::EdgeWeight>> {
         let (from, to) = edge_id.raw();
-        if let Some(neigh) = self.edges.get_mut(from) {
-            neigh.remove(to)
-        } else {
-            None
-        }
+        let edge = self.edges.get_mut(from)?.remove(to)?;
+        self.remove_source(from, to);
+        Some(edge)
     }
 
     fn remove_inbound(&mut self, node_id: NodeId<Self::Key>) {
-        for row in &mut self.edges {
-            row.remove(*node_id);
+        for from in std::mem::take(&mut self.inbound[*node_id]) {
+            self.edges[from].remove(*node_id);
         }
     }
 
     fn remove_outbound(&mut self, node_id: NodeId<Self::Key>) {
+        let targets = self.edges[*node_id].indices().collect::<Vec<_>>();
+
+        for to in targets {
+            self.remove_source(*node_id, to);
+        }
         self.edges[*node_id].clear();
     }
 
@@ -164,6 +182,15 @@ impl<W: Debug> RemoveEdge for AdjacencyMatrix<W> {
         for row in &mut self.edges {
             row.retain(|edge| visit(edge.into()))
         }
+
+        for sources in &mut self.inbound {
+            sources.clear();
+        }
+        for (from, row) in self.edges.iter().enumerate() {
+            for to in row.indices() {
+                self.inbound[to].push(from);
+            }
+        }
     }
 }
 
@@ -224,6 +251,26 @@ impl<W: Debug> EdgeIterAdjacent for AdjacencyMatrix<W> {
     }
 }
 
+impl<W: Debug> EdgeIterAdjacentInbound for AdjacencyMatrix<W> {
+    type InboundEdgeIds<'a>
+        = impl Iterator<Item = EdgeId<usize>> + 'a
+    where
+        Self: 'a;
+    type InboundEdges<'a>
+        = impl Iterator<Item = EdgeRef<'a, usize, W>> + 'a
+    where
+        W: 'a,
+        Self: 'a;
+
+    fn inbound_edge_ids(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdgeIds<'_> {
+        self.iter_inbound_edges(node_id).map(|edge| edge.edge_id)
+    }
+
+    fn iter_inbound_edges(&self, node_id: NodeId<Self::Key>) -> Self::InboundEdges<'_> {
+        self.col(*node_id)
+    }
+}
+
 impl<W: Debug> EdgeIterAdjacentMut for AdjacencyMatrix<W> {
     type EdgesMut<'a>
         = impl Iterator<Item = EdgeMut<'a, usize, W>> + 'a
@@ -242,13 +289,17 @@ impl<W: Debug + Clone + PartialEq> EdgeMap<usize, W> for AdjacencyMatrix<W> {}
 
 impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for AdjacencyMatrix<W> {
     fn new() -> Self {
-        Self { edges: Vec::new() }
+        Self {
+            edges: Vec::new(),
+            inbound: Vec::new(),
+        }
     }
 
     fn with_capacity(node_count: usize, _: usize) -> Self {
         let edges = Vec::with_capacity(node_count);
+        let inbound = Vec::with_capacity(node_count);
 
-        Self { edges }
+        Self { edges, inbound }
     }
 
     fn with_edges(
@@ -266,6 +317,9 @@ impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for AdjacencyMatrix<W>
         for row in &mut self.edges {
             row.clear()
         }
+        for sources in &mut self.inbound {
+            sources.clear()
+        }
     }
 
     fn allocate(&mut self, additional: usize) {
@@ -276,6 +330,7 @@ impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for AdjacencyMatrix<W>
         }
 
         self.edges.resize(size, StableVec::with_capacity(size));
+        self.inbound.resize_with(size, Vec::new);
     }
 }
 
@@ -283,7 +338,7 @@ impl<W: Debug + Clone + PartialEq> EdgeStorage<usize, W> for AdjacencyMatrix<W>
 mod test {
     use crate::edges::test::{
         edge_storage_adjacent, edge_storage_capacity, edge_storage_clear, edge_storage_count,
-        edge_storage_get, edge_storage_remove,
+        edge_storage_get, edge_storage_inbound, edge_storage_inbound_update, edge_storage_remove,
     };
 
     use super::AdjacencyMatrix;
@@ -311,4 +366,12 @@ mod test {
     fn dense_edge_storage_adjacent() {
         edge_storage_adjacent::<AdjacencyMatrix<f32>>()
     }
+    #[test]
+    fn dense_edge_storage_inbound() {
+        edge_storage_inbound::<AdjacencyMatrix<f32>>()
+    }
+    #[test]
+    fn dense_edge_storage_inbound_update() {
+        edge_storage_inbound_update::<AdjacencyMatrix<f32>>()
+    }
 }
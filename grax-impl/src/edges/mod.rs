@@ -1,7 +1,7 @@
 use grax_core::{
     collections::EdgeMap,
     edge::Edge,
-    graph::{EdgeIterAdjacent, EdgeIterAdjacentMut},
+    graph::{EdgeIterAdjacent, EdgeIterAdjacentInbound, EdgeIterAdjacentMut},
     index::{Identifier, NodeId},
 };
 
@@ -14,7 +14,12 @@ pub mod mat;
 pub mod test;
 
 pub trait EdgeStorage<K: Identifier, W>:
-    EdgeMap<K, W> + EdgeIterAdjacent + EdgeIterAdjacentMut + IntoIterator<Item = Edge<K, W>> + Sized
+    EdgeMap<K, W>
+    + EdgeIterAdjacent
+    + EdgeIterAdjacentInbound
+    + EdgeIterAdjacentMut
+    + IntoIterator<Item = Edge<K, W>>
+    + Sized
 {
     /// Creates a new EdgeStorage
     fn new() -> Self;
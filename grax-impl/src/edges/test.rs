@@ -115,3 +115,67 @@ pub fn edge_storage_adjacent<S: EdgeStorage<usize, f32>>() {
 
     assert!(!nodes.contains(&EdgeRef::new(edge_id(1, 0), &1.0)));
 }
+
+pub fn edge_storage_inbound<S: EdgeStorage<usize, f32>>() {
+    let mut storage = S::with_capacity(5, 10);
+
+    let zero = id(0);
+    let one = id(1);
+    let two = id(2);
+    let three = id(3);
+
+    storage.allocate(4);
+    storage.insert_edge(zero, one, 1.0);
+    storage.insert_edge(zero, three, 2.0);
+    storage.insert_edge(two, three, 3.0);
+
+    let edges = storage.iter_inbound_edges(three).collect::<Vec<_>>();
+
+    assert_eq!(edges.len(), 2);
+    assert!(edges.contains(&EdgeRef::new(edge_id(0, 3), &2.0)));
+    assert!(edges.contains(&EdgeRef::new(edge_id(2, 3), &3.0)));
+
+    assert_eq!(storage.inbound_edge_ids(zero).count(), 0);
+}
+
+pub fn edge_storage_inbound_update<S: EdgeStorage<usize, f32>>() {
+    let mut storage = S::with_capacity(5, 10);
+
+    let zero = id(0);
+    let one = id(1);
+    let two = id(2);
+    let three = id(3);
+
+    storage.allocate(4);
+    storage.insert_edge(zero, three, 1.0);
+    storage.insert_edge(one, three, 2.0);
+    storage.insert_edge(two, three, 3.0);
+    storage.insert_edge(three, zero, 4.0);
+    storage.insert_edge(one, zero, 5.0);
+
+    storage.remove_edge(edge_id(1, 3));
+    let mut sources = storage
+        .inbound_edge_ids(three)
+        .map(|edge_id| *edge_id.from())
+        .collect::<Vec<_>>();
+    sources.sort();
+    assert_eq!(sources, [0, 2]);
+
+    storage.remove_outbound(two);
+    assert_eq!(
+        storage.inbound_edge_ids(three).collect::<Vec<_>>(),
+        [edge_id(0, 3)]
+    );
+
+    storage.remove_inbound(zero);
+    assert_eq!(storage.inbound_edge_ids(zero).count(), 0);
+    assert_eq!(storage.edge_count(), 1);
+
+    storage.insert_edge(two, one, 6.0);
+    storage.retain_edges(|edge| *edge.weight > 5.0);
+    assert_eq!(storage.inbound_edge_ids(three).count(), 0);
+    assert_eq!(
+        storage.iter_inbound_edges(one).collect::<Vec<_>>(),
+        [EdgeRef::new(edge_id(2, 1), &6.0)]
+    );
+}
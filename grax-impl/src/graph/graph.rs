@@ -521,6 +521,29 @@ impl<NS: NodeStorage<usize, N>, ES: EdgeStorage<usize, W>, N: Debug, W: Debug, c
     }
 }
 
+impl<NS: NodeStorage<usize, N>, ES: EdgeStorage<usize, W>, N: Debug, W: Debug, const DI: bool>
+    EdgeIterAdjacentInbound for Graph<NS, ES, N, W, DI>
+{
+    type InboundEdgeIds<'a>
+        = impl Iterator<Item = EdgeId<Self::Key>> + 'a
+    where
+        Self: 'a;
+
+    type InboundEdges<'a>
+        = impl Iterator<Item = EdgeRef<'a, usize, W>> + 'a
+    where
+        W: 'a,
+        Self: 'a;
+
+    fn inbound_edge_ids<'a>(&'a self, node_id: NodeId<Self::Key>) -> Self::InboundEdgeIds<'a> {
+        self.edges.inbound_edge_ids(node_id)
+    }
+
+    fn iter_inbound_edges<'a>(&'a self, node_id: NodeId<Self::Key>) -> Self::InboundEdges<'a> {
+        self.edges.iter_inbound_edges(node_id)
+    }
+}
+
 impl<NS: NodeStorage<usize, N>, ES: EdgeStorage<usize, W>, N: Debug, W: Debug, const DI: bool>
     NodeIterAdjacentMut for Graph<NS, ES, N, W, DI>
 {
@@ -573,7 +596,7 @@ impl<NS: NodeStorage<usize, N>, ES: EdgeStorage<usize, W>, N: Debug, W: Debug, c
     type EdgeMap<V: Debug + Clone + PartialEq> = HashStorage<V>;
 
     fn fixed_edge_map<V: Debug + Clone + PartialEq>(&self, fill: V) -> Self::FixedEdgeMap<V> {
-        FixedEdgeVec::new(vec![fill; self.edge_count()], self.node_count())
+        FixedEdgeVec::new(self.node_count(), self.edge_ids(), fill)
     }
 
     fn edge_map<V: Debug + Clone + PartialEq>(&self) -> Self::EdgeMap<V> {
@@ -1,5 +1,4 @@
 #![feature(test)]
-#![feature(int_roundings)]
 #![feature(impl_trait_in_assoc_type)]
 
 pub mod edges;